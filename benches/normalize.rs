@@ -0,0 +1,43 @@
+use criterion::{black_box, criterion_group, criterion_main, BatchSize, Criterion};
+use malgebra::vector::{normalize_in_place, Vec2};
+
+// A frame's worth of particle velocities, with a length that isn't a multiple
+// of the group size so the scalar tail is exercised too
+fn particles() -> Vec<Vec2> {
+    (0..10_003)
+        .map(|i| {
+            let angle = i as f32 * 0.37;
+            Vec2::from_polar(1.0 + (i % 17) as f32, angle)
+        })
+        .collect()
+}
+
+fn normalize(c: &mut Criterion) {
+    let data = particles();
+    let mut group = c.benchmark_group("normalize");
+
+    group.bench_function("normalize_in_place", |b| {
+        b.iter_batched_ref(
+            || data.clone(),
+            |v| normalize_in_place(black_box(v)),
+            BatchSize::SmallInput,
+        )
+    });
+
+    group.bench_function("scalar_loop", |b| {
+        b.iter_batched_ref(
+            || data.clone(),
+            |v| {
+                for vec in black_box(v).iter_mut() {
+                    *vec = vec.normalize();
+                }
+            },
+            BatchSize::SmallInput,
+        )
+    });
+
+    group.finish();
+}
+
+criterion_group!(benches, normalize);
+criterion_main!(benches);
@@ -0,0 +1,151 @@
+use crate::{One, Zero};
+use core::{
+    fmt,
+    ops::{Add, Mul, Neg, Sub},
+};
+
+/// Complex number `re + im * i` over an element type with infallible ops.
+/// Types whose ops return `Option`, like `CheckRdc`, are not supported
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Complex<T> {
+    pub re: T,
+    pub im: T,
+}
+
+impl<T> Complex<T> {
+    pub const fn new(re: T, im: T) -> Self {
+        Self { re, im }
+    }
+}
+
+impl<T: Zero + One> Complex<T> {
+    /// Imaginary unit
+    pub const I: Self = Self {
+        re: T::ZERO,
+        im: T::ONE,
+    };
+}
+
+impl<T: Clone + Neg<Output = T>> Complex<T> {
+    pub fn conj(&self) -> Self {
+        Self::new(self.re.clone(), -self.im.clone())
+    }
+}
+
+impl<T: Clone + Add<Output = T> + Mul<Output = T>> Complex<T> {
+    /// `re^2 + im^2`
+    pub fn norm_sqr(&self) -> T {
+        self.re.clone() * self.re.clone() + self.im.clone() * self.im.clone()
+    }
+}
+
+impl<T: Add<Output = T>> Add for Complex<T> {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        Self::new(self.re + rhs.re, self.im + rhs.im)
+    }
+}
+
+impl<T: Sub<Output = T>> Sub for Complex<T> {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        Self::new(self.re - rhs.re, self.im - rhs.im)
+    }
+}
+
+impl<T: Clone + Add<Output = T> + Sub<Output = T> + Mul<Output = T>> Mul for Complex<T> {
+    type Output = Self;
+
+    fn mul(self, rhs: Self) -> Self::Output {
+        let re = self.re.clone() * rhs.re.clone() - self.im.clone() * rhs.im.clone();
+        let im = self.re * rhs.im + self.im * rhs.re;
+
+        Self::new(re, im)
+    }
+}
+
+impl<T: Neg<Output = T>> Neg for Complex<T> {
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        Self::new(-self.re, -self.im)
+    }
+}
+
+impl<T: Zero> Zero for Complex<T> {
+    const ZERO: Self = Self {
+        re: T::ZERO,
+        im: T::ZERO,
+    };
+}
+
+impl<T: Zero + One> One for Complex<T> {
+    const ONE: Self = Self {
+        re: T::ONE,
+        im: T::ZERO,
+    };
+}
+
+/// Prints `a + bi`, or `a - bi` when the imaginary part is negative.
+/// Width, precision and the other format flags apply to both parts.
+/// The negative imaginary part is printed through `Neg`, so an integer `MIN` overflows like in `conj`
+impl<T: fmt::Display + Zero + PartialOrd + Clone + Neg<Output = T>> fmt::Display for Complex<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&self.re, f)?;
+
+        if self.im < T::ZERO {
+            f.write_str(" - ")?;
+            fmt::Display::fmt(&-self.im.clone(), f)?;
+        } else {
+            f.write_str(" + ")?;
+            fmt::Display::fmt(&self.im, f)?;
+        }
+
+        f.write_str("i")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tests::Buf;
+
+    #[test]
+    fn mul() {
+        let lhs = Complex::new(1, 2);
+        let rhs = Complex::new(3, 4);
+
+        assert_eq!(lhs * rhs, Complex::new(-5, 10));
+        assert_eq!(Complex::<i32>::I * Complex::I, -Complex::ONE);
+        assert_eq!(lhs.conj().norm_sqr(), 5);
+    }
+
+    #[test]
+    fn display_sign() {
+        let show = |val: Complex<i32>| Buf::format(format_args!("{val}"));
+
+        assert_eq!(show(Complex::new(1, 2)).as_str(), "1 + 2i");
+        assert_eq!(show(Complex::new(1, -2)).as_str(), "1 - 2i");
+        assert_eq!(show(Complex::new(-1, 0)).as_str(), "-1 + 0i");
+    }
+
+    #[test]
+    fn display_honours_width_and_precision() {
+        let val = Complex::new(1.0, -0.25);
+
+        assert_eq!(
+            Buf::format(format_args!("{val:.2}")).as_str(),
+            "1.00 - 0.25i"
+        );
+        assert_eq!(
+            Buf::format(format_args!("{val:4}")).as_str(),
+            "   1 - 0.25i"
+        );
+        assert_eq!(
+            Buf::format(format_args!("{:03}", Complex::new(-1, 2))).as_str(),
+            "-01 + 002i"
+        );
+    }
+}
@@ -1,5 +1,12 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+
+pub mod complex;
+#[cfg(feature = "std")]
+pub mod matrix;
 pub mod primitives;
 pub mod reducible;
+#[cfg(feature = "std")]
+pub mod vector;
 
 pub trait CheckGcd: Sized + Checked {
     fn gcd(&self, rhs: &Self) -> Option<Self>;
@@ -10,6 +17,14 @@ pub trait Gcd: Sized {
     fn gcd(&self, rhs: &Self) -> Self;
 }
 
+/// Any `Gcd` type opting into `Checked` gets a `CheckGcd` that never fails,
+/// so it can be used as a `CheckRdc` element
+impl<T: Gcd + Checked> CheckGcd for T {
+    fn gcd(&self, rhs: &Self) -> Option<Self> {
+        Some(Gcd::gcd(self, rhs))
+    }
+}
+
 pub trait Zero {
     const ZERO: Self;
 
@@ -38,3 +53,183 @@ pub trait One {
         Self::ONE
     }
 }
+
+/// Implements `Zero` and `One` from the given constants:
+/// `impl_zero_one!(MyInt: MyInt(0), MyInt(1); Other: Other::ZERO, Other::ONE)`
+#[macro_export]
+macro_rules! impl_zero_one {
+    ($($typ:ty: $zero:expr, $one:expr);* $(;)?) => {
+        $(
+            impl $crate::Zero for $typ {
+                const ZERO: Self = $zero;
+            }
+
+            impl $crate::One for $typ {
+                const ONE: Self = $one;
+            }
+        )*
+    };
+}
+
+impl_zero_one! {
+    u8: 0, 1; u16: 0, 1; u32: 0, 1; u64: 0, 1; u128: 0, 1; usize: 0, 1;
+    i8: 0, 1; i16: 0, 1; i32: 0, 1; i64: 0, 1; i128: 0, 1; isize: 0, 1;
+    f32: 0.0, 1.0; f64: 0.0, 1.0
+}
+
+pub trait Pow<Exp> {
+    type Output;
+
+    fn pow(self, exp: Exp) -> Self::Output;
+}
+
+/// Multiplicative inverse. Fallible implementors signal a value without an
+/// inverse (like a zero fraction) through their `Output`
+pub trait Inv {
+    type Output;
+
+    fn inv(self) -> Self::Output;
+}
+
+/// Integer square root, rounded down. `None` for negative values
+pub trait ISqrt: Sized {
+    fn isqrt(&self) -> Option<Self>;
+}
+
+/// Lossy conversion to `f64`, rounding like an `as` cast
+pub trait AsF64 {
+    fn as_f64(&self) -> f64;
+}
+
+/// Element type with a wider counterpart that holds every value of `Self`
+pub trait Widen: Sized {
+    type Wide: From<Self>;
+}
+
+pub trait Signed: Sized {
+    fn abs(&self) -> Option<Self>;
+
+    fn signum(&self) -> Self;
+
+    fn is_positive(&self) -> bool;
+
+    fn is_negative(&self) -> bool;
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::primitives::check_int::CheckI64;
+    use crate::reducible::checked_reducible::CheckRdc;
+    use core::fmt::Write;
+
+    /// Fixed-size sink shared by the formatting tests, so they run without an allocator
+    pub(crate) struct Buf {
+        bytes: [u8; 32],
+        len: usize,
+    }
+
+    impl Buf {
+        pub(crate) fn format(args: core::fmt::Arguments<'_>) -> Self {
+            let mut buf = Buf {
+                bytes: [0; 32],
+                len: 0,
+            };
+            buf.write_fmt(args).unwrap();
+
+            buf
+        }
+
+        pub(crate) fn as_str(&self) -> &str {
+            core::str::from_utf8(&self.bytes[..self.len]).unwrap()
+        }
+    }
+
+    impl Write for Buf {
+        fn write_str(&mut self, s: &str) -> core::fmt::Result {
+            let end = self.len + s.len();
+            self.bytes
+                .get_mut(self.len..end)
+                .ok_or(core::fmt::Error)?
+                .copy_from_slice(s.as_bytes());
+            self.len = end;
+
+            Ok(())
+        }
+    }
+
+    // Uses only `core` items, so it also runs with `--no-default-features`
+    #[test]
+    fn fractions_work_on_core() {
+        let third = CheckRdc::<CheckI64>::new(CheckI64::new(1), CheckI64::new(3));
+        let sixth = CheckRdc::<CheckI64>::new(CheckI64::new(1), CheckI64::new(6));
+        let half = (&third + &sixth).unwrap().reduce().unwrap();
+
+        assert_eq!(Buf::format(format_args!("{half}")).as_str(), "(1)/(2)");
+    }
+
+    #[derive(Debug, PartialEq)]
+    struct Cents(i64);
+
+    impl_zero_one!(Cents: Cents(0), Cents(100));
+
+    #[test]
+    fn impl_zero_one_wires_up_a_newtype() {
+        use crate::{One, Zero};
+
+        assert_eq!(Cents::ZERO, Cents(0));
+        assert_eq!(Cents::ONE, Cents(100));
+        assert!(Cents(0).is_zero());
+        assert!(Cents(100).is_one());
+        assert!(!Cents(1).is_one());
+        assert_eq!(Cents::non_zero(), Cents(100));
+    }
+
+    // Only `Gcd` is implemented, `CheckGcd` comes from the blanket impl
+    #[derive(Clone, Debug, PartialEq)]
+    struct Small(u16);
+
+    impl_zero_one!(Small: Small(0), Small(1));
+
+    impl crate::Gcd for Small {
+        fn gcd(&self, rhs: &Self) -> Self {
+            let (mut lhs, mut rhs) = (self.0, rhs.0);
+
+            while rhs != 0 {
+                (lhs, rhs) = (rhs, lhs % rhs);
+            }
+
+            Small(lhs)
+        }
+    }
+
+    impl crate::Checked for Small {}
+
+    impl core::ops::Div for &Small {
+        type Output = Option<Small>;
+
+        fn div(self, rhs: Self) -> Self::Output {
+            self.0.checked_div(rhs.0).map(Small)
+        }
+    }
+
+    impl core::ops::Mul for &Small {
+        type Output = Option<Small>;
+
+        fn mul(self, rhs: Self) -> Self::Output {
+            self.0.checked_mul(rhs.0).map(Small)
+        }
+    }
+
+    #[test]
+    fn gcd_only_type_becomes_an_element() {
+        use crate::CheckGcd;
+
+        assert_eq!(CheckGcd::gcd(&Small(12), &Small(18)), Some(Small(6)));
+
+        let val = CheckRdc::<Small>::new(Small(6), Small(8));
+        assert_eq!(val.as_parts(), (&Small(3), &Small(4)));
+
+        let squared = (&val * &val).unwrap().reduce().unwrap();
+        assert_eq!(squared.into_parts(), (Small(9), Small(16)));
+    }
+}
@@ -0,0 +1,347 @@
+use crate::vector::{Vec2, Vec3};
+use crate::{Inv, One, Zero};
+use core::ops::Mul;
+
+/// 2x2 matrix stored as two `Vec2` columns. A linear transform of 2D vectors
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Mat2 {
+    cols: [Vec2; 2],
+}
+
+impl Mat2 {
+    /// Takes the entries row by row, so the matrix reads `[[a, b], [c, d]]`
+    pub const fn new(a: f32, b: f32, c: f32, d: f32) -> Self {
+        Self::from_cols(Vec2::new(a, c), Vec2::new(b, d))
+    }
+
+    pub const fn from_cols(x_axis: Vec2, y_axis: Vec2) -> Self {
+        Self {
+            cols: [x_axis, y_axis],
+        }
+    }
+
+    pub const fn identity() -> Self {
+        Self::ONE
+    }
+
+    pub const fn zero() -> Self {
+        Self::ZERO
+    }
+
+    pub const fn col(&self, index: usize) -> Vec2 {
+        self.cols[index]
+    }
+
+    /// Counterclockwise rotation by `angle` radians
+    pub fn from_angle(angle: f32) -> Self {
+        let (sin, cos) = angle.sin_cos();
+
+        Self::from_cols(Vec2::new(cos, sin), Vec2::new(-sin, cos))
+    }
+
+    pub fn mul_vec2(&self, rhs: Vec2) -> Vec2 {
+        self.cols[0] * rhs.x() + self.cols[1] * rhs.y()
+    }
+
+    pub fn transpose(&self) -> Self {
+        let [x, y] = self.cols;
+
+        Self::from_cols(Vec2::new(x.x(), y.x()), Vec2::new(x.y(), y.y()))
+    }
+
+    pub fn determinant(&self) -> f32 {
+        self.cols[0].perp_dot(&self.cols[1])
+    }
+
+    /// Returns `None` for a singular matrix. Nearly singular matrices still get an
+    /// inverse with huge entries, see `try_inverse`
+    pub fn inverse(&self) -> Option<Self> {
+        self.try_inverse(0.0)
+    }
+
+    /// Returns `None` when `|determinant| <= eps`
+    pub fn try_inverse(&self, eps: f32) -> Option<Self> {
+        let det = self.determinant();
+
+        if det.abs() <= eps {
+            return None;
+        }
+
+        let [x, y] = self.cols;
+
+        // [[a, b], [c, d]]^-1 = [[d, -b], [-c, a]] / det
+        Some(Self::new(y.y(), -y.x(), -x.y(), x.x()) * (1.0 / det))
+    }
+}
+
+/// Same as `Mat2::inverse`, `None` for a singular matrix
+impl Inv for Mat2 {
+    type Output = Option<Self>;
+
+    fn inv(self) -> Self::Output {
+        self.inverse()
+    }
+}
+
+impl Mul<f32> for Mat2 {
+    type Output = Self;
+
+    fn mul(self, rhs: f32) -> Self::Output {
+        Self {
+            cols: self.cols.map(|col| col * rhs),
+        }
+    }
+}
+
+impl Mul for Mat2 {
+    type Output = Self;
+
+    fn mul(self, rhs: Self) -> Self::Output {
+        Self {
+            cols: rhs.cols.map(|col| self.mul_vec2(col)),
+        }
+    }
+}
+
+impl Mul<Vec2> for Mat2 {
+    type Output = Vec2;
+
+    fn mul(self, rhs: Vec2) -> Self::Output {
+        self.mul_vec2(rhs)
+    }
+}
+
+impl Zero for Mat2 {
+    const ZERO: Self = Self {
+        cols: [Vec2::ZERO; 2],
+    };
+}
+
+impl One for Mat2 {
+    const ONE: Self = Self::from_cols(Vec2::X, Vec2::Y);
+}
+
+impl Default for Mat2 {
+    fn default() -> Self {
+        Self::ONE
+    }
+}
+
+/// 3x3 matrix stored as three `Vec3` columns.
+/// Used as an affine transform of 2D points in homogeneous coordinates
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Mat3 {
+    cols: [Vec3; 3],
+}
+
+impl Mat3 {
+    pub const fn from_cols(x_axis: Vec3, y_axis: Vec3, z_axis: Vec3) -> Self {
+        Self {
+            cols: [x_axis, y_axis, z_axis],
+        }
+    }
+
+    pub const fn identity() -> Self {
+        Self::ONE
+    }
+
+    pub const fn col(&self, index: usize) -> Vec3 {
+        self.cols[index]
+    }
+
+    pub fn from_translation(translation: Vec2) -> Self {
+        Self::from_cols(
+            Vec3::new(1.0, 0.0, 0.0),
+            Vec3::new(0.0, 1.0, 0.0),
+            Vec3::new(translation.x(), translation.y(), 1.0),
+        )
+    }
+
+    pub fn from_scale(scale: Vec2) -> Self {
+        Self::from_cols(
+            Vec3::new(scale.x(), 0.0, 0.0),
+            Vec3::new(0.0, scale.y(), 0.0),
+            Vec3::new(0.0, 0.0, 1.0),
+        )
+    }
+
+    /// Counterclockwise rotation by `angle` radians
+    pub fn from_angle(angle: f32) -> Self {
+        let (sin, cos) = angle.sin_cos();
+
+        Self::from_cols(
+            Vec3::new(cos, sin, 0.0),
+            Vec3::new(-sin, cos, 0.0),
+            Vec3::new(0.0, 0.0, 1.0),
+        )
+    }
+
+    pub fn mul_vec3(&self, rhs: Vec3) -> Vec3 {
+        self.cols[0] * rhs.x() + self.cols[1] * rhs.y() + self.cols[2] * rhs.z()
+    }
+
+    /// Applies the transform to the point `(x, y, 1)`
+    pub fn transform_point2(&self, point: Vec2) -> Vec2 {
+        let res = self.mul_vec3(Vec3::new(point.x(), point.y(), 1.0));
+
+        Vec2::new(res.x(), res.y())
+    }
+
+    pub fn transpose(&self) -> Self {
+        let [x, y, z] = self.cols;
+
+        Self::from_cols(
+            Vec3::new(x.x(), y.x(), z.x()),
+            Vec3::new(x.y(), y.y(), z.y()),
+            Vec3::new(x.z(), y.z(), z.z()),
+        )
+    }
+
+    pub fn determinant(&self) -> f32 {
+        self.cols[0].dot(&self.cols[1].cross(&self.cols[2]))
+    }
+
+    /// Returns `None` for a singular matrix
+    pub fn inverse(&self) -> Option<Self> {
+        let det = self.determinant();
+
+        if det == 0.0 {
+            return None;
+        }
+
+        let [x, y, z] = self.cols;
+
+        // rows of the inverse are the cross products of the columns
+        let rows = Self::from_cols(y.cross(&z), z.cross(&x), x.cross(&y));
+
+        Some(Self {
+            cols: rows.transpose().cols.map(|col| col / det),
+        })
+    }
+}
+
+impl Mul for Mat3 {
+    type Output = Self;
+
+    fn mul(self, rhs: Self) -> Self::Output {
+        Self {
+            cols: rhs.cols.map(|col| self.mul_vec3(col)),
+        }
+    }
+}
+
+impl Mul<Vec3> for Mat3 {
+    type Output = Vec3;
+
+    fn mul(self, rhs: Vec3) -> Self::Output {
+        self.mul_vec3(rhs)
+    }
+}
+
+impl Zero for Mat3 {
+    const ZERO: Self = Self {
+        cols: [Vec3::ZERO; 3],
+    };
+}
+
+impl One for Mat3 {
+    const ONE: Self = Self::from_cols(
+        Vec3::new(1.0, 0.0, 0.0),
+        Vec3::new(0.0, 1.0, 0.0),
+        Vec3::new(0.0, 0.0, 1.0),
+    );
+}
+
+impl Default for Mat3 {
+    fn default() -> Self {
+        Self::ONE
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn mat2_identity() {
+        let m = Mat2::new(1.0, 2.0, 3.0, 4.0);
+        let v = Vec2::new(5.0, -6.0);
+
+        assert_eq!(Mat2::identity() * m, m);
+        assert_eq!(m * Mat2::identity(), m);
+        assert_eq!(Mat2::identity() * v, v);
+        assert_eq!(Mat2::zero() * v, Vec2::ZERO);
+        assert_eq!(m.determinant(), -2.0);
+        assert_eq!(m.transpose(), Mat2::new(1.0, 3.0, 2.0, 4.0));
+        assert_eq!(m * v, Vec2::new(-7.0, -9.0));
+    }
+
+    #[test]
+    fn mat2_rotation() {
+        let quarter = Mat2::from_angle(core::f32::consts::FRAC_PI_2);
+
+        assert!((quarter * Vec2::X).abs_diff_eq(&Vec2::Y, 1e-6));
+        assert!((quarter * quarter * Vec2::X).abs_diff_eq(&Vec2::NEG_X, 1e-6));
+        assert!((quarter.determinant() - 1.0).abs() <= 1e-6);
+
+        let v = Vec2::new(3.0, 4.0);
+        assert!((Mat2::from_angle(0.3) * v).abs_diff_eq(&v.rotate(0.3), 1e-6));
+    }
+
+    #[test]
+    fn mat2_mul_is_associative() {
+        let a = Mat2::new(1.0, 2.0, 3.0, 4.0);
+        let b = Mat2::new(0.0, -1.0, 5.0, 2.0);
+        let c = Mat2::new(2.0, 0.5, -3.0, 1.0);
+
+        // small integers and halves, so both groupings are exact
+        assert_eq!((a * b) * c, a * (b * c));
+        assert_eq!((a * b) * Vec2::X, a * (b * Vec2::X));
+    }
+
+    #[test]
+    fn mat2_inverse() {
+        let m = Mat2::new(4.0, 7.0, 2.0, 6.0);
+        let inv = m.inverse().unwrap();
+        let prod = m * inv;
+
+        for i in 0..2 {
+            assert!(prod.col(i).abs_diff_eq(&Mat2::identity().col(i), 1e-6));
+        }
+
+        assert_eq!(Mat2::new(1.0, 2.0, 2.0, 4.0).inverse(), None);
+
+        let nearly_singular = Mat2::new(1.0, 2.0, 1.0, 2.0001);
+        assert!(nearly_singular.inverse().is_some());
+        assert_eq!(nearly_singular.try_inverse(1e-3), None);
+    }
+
+    #[test]
+    fn mat2_inv_matches_inverse() {
+        let m = Mat2::new(2.0, 0.0, 0.0, -4.0);
+
+        assert_eq!(m.inv(), Some(Mat2::new(0.5, 0.0, 0.0, -0.25)));
+        assert_eq!(Mat2::zero().inv(), None);
+    }
+
+    #[test]
+    fn mat3_rotate_then_translate() {
+        let rotate = Mat3::from_angle(core::f32::consts::FRAC_PI_2);
+        let translate = Mat3::from_translation(Vec2::new(10.0, 0.0));
+        let transform = translate * rotate;
+
+        let point = transform.transform_point2(Vec2::new(1.0, 0.0));
+        assert!(point.abs_diff_eq(&Vec2::new(10.0, 1.0), 1e-6));
+
+        // the other order translates first, then rotates the translation too
+        let point = (rotate * translate).transform_point2(Vec2::new(1.0, 0.0));
+        assert!(point.abs_diff_eq(&Vec2::new(0.0, 11.0), 1e-5));
+
+        let back = transform
+            .inverse()
+            .unwrap()
+            .transform_point2(Vec2::new(10.0, 1.0));
+        assert!(back.abs_diff_eq(&Vec2::new(1.0, 0.0), 1e-6));
+        assert!((transform.determinant() - 1.0).abs() <= 1e-6);
+    }
+}
@@ -0,0 +1,175 @@
+use crate::{CheckGcd, Checked};
+use core::{
+    fmt,
+    ops::{Add, Div, Mul, Neg, Rem, Sub},
+};
+use paste::paste;
+
+/// Element for `CheckRdc` whose add, sub, mul, div and neg wrap around instead of
+/// returning `None`. Only division and remainder by zero fail, and the gcd stays exact.
+/// Once a part wraps, the fraction silently stops being the exact result
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct WrappingInt<T>(pub T);
+
+/// Element for `CheckRdc` whose add, sub, mul, div and neg saturate instead of
+/// returning `None`. Only division and remainder by zero fail, and the gcd stays exact.
+/// Saturated parts lose the ratio, e.g. two overflowing parts can give `MAX/MAX == 1`
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct SaturatingInt<T>(pub T);
+
+macro_rules! impl_adapter {
+    ($name:ident, $mode:ident: $($typ:ty),*) => {
+        $(
+            paste! {
+                impl Add for &$name<$typ> {
+                    type Output = Option<$name<$typ>>;
+
+                    fn add(self, rhs: Self) -> Self::Output {
+                        Some($name(self.0.[<$mode _add>](rhs.0)))
+                    }
+                }
+
+                impl Sub for &$name<$typ> {
+                    type Output = Option<$name<$typ>>;
+
+                    fn sub(self, rhs: Self) -> Self::Output {
+                        Some($name(self.0.[<$mode _sub>](rhs.0)))
+                    }
+                }
+
+                impl Mul for &$name<$typ> {
+                    type Output = Option<$name<$typ>>;
+
+                    fn mul(self, rhs: Self) -> Self::Output {
+                        Some($name(self.0.[<$mode _mul>](rhs.0)))
+                    }
+                }
+
+                impl Div for &$name<$typ> {
+                    type Output = Option<$name<$typ>>;
+
+                    fn div(self, rhs: Self) -> Self::Output {
+                        if rhs.0 == 0 {
+                            return None;
+                        }
+
+                        Some($name(self.0.[<$mode _div>](rhs.0)))
+                    }
+                }
+
+                // `MIN % -1` is mathematically zero, which is what wrapping_rem returns
+                impl Rem for &$name<$typ> {
+                    type Output = Option<$name<$typ>>;
+
+                    fn rem(self, rhs: Self) -> Self::Output {
+                        if rhs.0 == 0 {
+                            return None;
+                        }
+
+                        Some($name(self.0.wrapping_rem(rhs.0)))
+                    }
+                }
+            }
+
+            impl Checked for $name<$typ> {}
+
+            crate::impl_zero_one!($name<$typ>: $name(0), $name(1));
+
+            impl fmt::Display for $name<$typ> {
+                fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                    fmt::Display::fmt(&self.0, f)
+                }
+            }
+        )*
+    };
+
+    (unsigned - $name:ident: $($typ:ty),*) => {
+        $(
+            impl CheckGcd for $name<$typ> {
+                fn gcd(&self, rhs: &Self) -> Option<Self> {
+                    let (mut lhs, mut rhs) = (self.0, rhs.0);
+
+                    while rhs != 0 {
+                        (lhs, rhs) = (rhs, lhs % rhs);
+                    }
+
+                    Some($name(lhs))
+                }
+            }
+        )*
+    };
+
+    (signed - $name:ident, $mode:ident: $($typ:ty),*) => {
+        $(
+            impl CheckGcd for $name<$typ> {
+                fn gcd(&self, rhs: &Self) -> Option<Self> {
+                    let (mut lhs, mut rhs) = (self.0, rhs.0);
+
+                    while rhs != 0 {
+                        (lhs, rhs) = (rhs, lhs.wrapping_rem(rhs));
+                    }
+
+                    // the loop leaves the sign of one of the inputs
+                    lhs.checked_abs().map($name)
+                }
+            }
+
+            paste! {
+                impl Neg for &$name<$typ> {
+                    type Output = Option<$name<$typ>>;
+
+                    fn neg(self) -> Self::Output {
+                        Some($name(self.0.[<$mode _neg>]()))
+                    }
+                }
+            }
+        )*
+    };
+}
+
+impl_adapter!(WrappingInt, wrapping: u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
+impl_adapter!(SaturatingInt, saturating: u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
+
+impl_adapter!(unsigned - WrappingInt: u8, u16, u32, u64, u128, usize);
+impl_adapter!(unsigned - SaturatingInt: u8, u16, u32, u64, u128, usize);
+
+impl_adapter!(signed - WrappingInt, wrapping: i8, i16, i32, i64, i128, isize);
+impl_adapter!(signed - SaturatingInt, saturating: i8, i16, i32, i64, i128, isize);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::primitives::check_int::CheckU8;
+    use crate::reducible::checked_reducible::CheckRdc;
+
+    fn wrapping(num: u8) -> CheckRdc<WrappingInt<u8>> {
+        CheckRdc::<WrappingInt<u8>>::new(WrappingInt(num), WrappingInt(1))
+    }
+
+    fn saturating(num: u8) -> CheckRdc<SaturatingInt<u8>> {
+        CheckRdc::<SaturatingInt<u8>>::new(SaturatingInt(num), SaturatingInt(1))
+    }
+
+    #[test]
+    fn overflow_behaviors_differ() {
+        let sum = (&wrapping(200) + &wrapping(100)).unwrap();
+        assert_eq!(sum.into_parts(), (WrappingInt(44), WrappingInt(1)));
+
+        let sum = (&saturating(200) + &saturating(100)).unwrap();
+        assert_eq!(sum.into_parts(), (SaturatingInt(255), SaturatingInt(1)));
+
+        let checked = |num| CheckRdc::<CheckU8>::new(CheckU8::new(num), CheckU8::new(1));
+        assert_eq!(&checked(200) + &checked(100), None);
+    }
+
+    #[test]
+    fn signed_adapters() {
+        let min = WrappingInt(i8::MIN);
+
+        assert_eq!(-&min, Some(min));
+        assert_eq!(-&SaturatingInt(i8::MIN), Some(SaturatingInt(i8::MAX)));
+        assert_eq!(&min / &WrappingInt(0), None);
+        assert_eq!(min.gcd(&WrappingInt(-64)), Some(WrappingInt(64)));
+        assert_eq!(min.gcd(&min), None);
+    }
+}
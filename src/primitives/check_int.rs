@@ -1,11 +1,26 @@
-use crate::{CheckGcd, Checked};
+use crate::{AsF64, CheckGcd, Checked, ISqrt, Pow, Signed, Widen};
+use core::ops::{Add, BitAnd, BitOr, BitXor, Div, Mul, Neg, Not, Rem, Shl, Shr, Sub};
 use paste::paste;
-use std::ops::{Add, BitAnd, BitOr, BitXor, Div, Mul, Neg, Not, Rem, Shl, Shr, Sub};
+
+#[cfg(feature = "num-rational")]
+use crate::reducible::checked_reducible::{ArithError, CheckRdc};
 
 pub trait IntoCheck<U> {
     fn safe(self) -> U;
 }
 
+/// Returned when an integer has more significant bits than `f64` can hold exactly
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct InexactFloatError;
+
+impl core::fmt::Display for InexactFloatError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "integer is not exactly representable as f64")
+    }
+}
+
+impl core::error::Error for InexactFloatError {}
+
 macro_rules! impl_ops {
     (shift - $typ:ty, $name:ident: $(($op:ident, $fn_name:ident));*) => {
         $(
@@ -208,8 +223,8 @@ macro_rules! impl_method {
 macro_rules! implFormatTrait {
     ($name:ident: $($trait:ident),*) => {
         $(
-            impl std::fmt::$trait for $name {
-                fn fmt (&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result{
+            impl core::fmt::$trait for $name {
+                fn fmt (&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result{
                     self.0.fmt(f)
                 }
             }
@@ -217,6 +232,55 @@ macro_rules! implFormatTrait {
     };
 }
 
+macro_rules! impl_try_into_f64 {
+    ($name:ident, |$val:ident| $magnitude:expr) => {
+        impl TryFrom<$name> for f64 {
+            type Error = InexactFloatError;
+
+            fn try_from($val: $name) -> Result<f64, Self::Error> {
+                let magnitude = $magnitude;
+
+                if magnitude != 0
+                    && magnitude.ilog2() - magnitude.trailing_zeros() >= f64::MANTISSA_DIGITS
+                {
+                    return Err(InexactFloatError);
+                }
+
+                Ok($val.0 as f64)
+            }
+        }
+    };
+}
+
+macro_rules! impl_ratio_conversions {
+    ($typ:ty, $name:ident) => {
+        /// Reduces the fraction first, since `Ratio` expects reduced parts.
+        /// Fails if the reduction overflows
+        #[cfg(feature = "num-rational")]
+        impl TryFrom<CheckRdc<$name>> for num_rational::Ratio<$typ> {
+            type Error = ArithError;
+
+            fn try_from(mut value: CheckRdc<$name>) -> Result<Self, Self::Error> {
+                value.checked_simplify()?;
+                let (num, denom) = value.into_parts();
+
+                Ok(num_rational::Ratio::new_raw(num.0, denom.0))
+            }
+        }
+
+        #[cfg(feature = "num-rational")]
+        impl TryFrom<num_rational::Ratio<$typ>> for CheckRdc<$name> {
+            type Error = ArithError;
+
+            fn try_from(value: num_rational::Ratio<$typ>) -> Result<Self, Self::Error> {
+                let (num, denom) = value.into_raw();
+
+                CheckRdc::<$name>::try_new($name(num), $name(denom))
+            }
+        }
+    };
+}
+
 macro_rules! define {
     ($($typ:ident);*) => {
         $(
@@ -232,6 +296,7 @@ macro_rules! define_ {
         /// Main struct for working with checked primitives.
         /// It is very close to std primitives in its functionality
         #[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash, PartialOrd, Ord)]
+        #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize), serde(transparent))]
         pub struct $name ($typ);
 
         impl $name {
@@ -261,7 +326,7 @@ macro_rules! define_ {
             );
 
 
-            pub fn from_str_radix(src: &str, radix: CheckU32) -> Result<Self, std::num::ParseIntError> {
+            pub fn from_str_radix(src: &str, radix: CheckU32) -> Result<Self, core::num::ParseIntError> {
                 let res = <$typ>::from_str_radix(src, radix.0);
                 match res {
                     Ok(val) => Ok(Self(val)),
@@ -302,6 +367,8 @@ macro_rules! define_ {
             }
         }
 
+        crate::impl_zero_one!($name: Self(0), Self(1));
+
         impl IntoCheck<$name> for $typ {
             fn safe(self) -> $name {
                 $name(self)
@@ -314,6 +381,22 @@ macro_rules! define_ {
 
         impl_ops!(shift - $typ, $name: (Shl, shl); (Shr, shr));
 
+        impl Pow<CheckU32> for $name {
+            type Output = Option<$name>;
+
+            fn pow(self, exp: CheckU32) -> Self::Output {
+                $name::pow(self, exp)
+            }
+        }
+
+        impl Pow<u32> for $name {
+            type Output = Option<$name>;
+
+            fn pow(self, exp: u32) -> Self::Output {
+                $name::pow(self, CheckU32(exp))
+            }
+        }
+
         impl Neg for $name {
             type Output = Option<$name>;
 
@@ -328,10 +411,10 @@ macro_rules! define_ {
 
         implFormatTrait!($name: Display, Binary, LowerExp, LowerHex, Octal, UpperExp, UpperHex);
 
-        impl std::str::FromStr for $name {
-            type Err = std::num::ParseIntError;
+        impl core::str::FromStr for $name {
+            type Err = core::num::ParseIntError;
 
-            fn from_str(src: &str) -> Result<Self, std::num::ParseIntError> {
+            fn from_str(src: &str) -> Result<Self, core::num::ParseIntError> {
                 match <$typ>::from_str(src) {
                     Ok(val) => Ok(Self(val)),
                     Err(e) => Err(e)
@@ -440,6 +523,22 @@ macro_rules! define_unsigned_ {
 
             impl Checked for $name{}
 
+            impl_try_into_f64!($name, |value| value.0);
+
+            impl AsF64 for $name {
+                fn as_f64(&self) -> f64 {
+                    self.0 as f64
+                }
+            }
+
+            impl_ratio_conversions!($typ, $name);
+
+            impl ISqrt for $name {
+                fn isqrt(&self) -> Option<Self> {
+                    Some(Self(self.0.isqrt()))
+                }
+            }
+
             impl CheckGcd for $name {
                 fn gcd(&self, rhs: &Self) -> Option<Self> {
                     self.gcd_sign_unsafe(rhs)
@@ -482,10 +581,48 @@ macro_rules! define_signed_ {
                     None
                 }
             }
+
+            pub const fn signum(self) -> Self {
+                Self(self.0.signum())
+            }
+        }
+
+        impl Signed for $name {
+            fn abs(&self) -> Option<Self> {
+                $name::abs(*self)
+            }
+
+            fn signum(&self) -> Self {
+                $name::signum(*self)
+            }
+
+            fn is_positive(&self) -> bool {
+                $name::is_positive(*self)
+            }
+
+            fn is_negative(&self) -> bool {
+                $name::is_negative(*self)
+            }
         }
 
         impl Checked for $name {}
 
+        impl_try_into_f64!($name, |value| value.0.unsigned_abs());
+
+        impl AsF64 for $name {
+            fn as_f64(&self) -> f64 {
+                self.0 as f64
+            }
+        }
+
+        impl_ratio_conversions!($typ, $name);
+
+        impl ISqrt for $name {
+            fn isqrt(&self) -> Option<Self> {
+                self.0.checked_isqrt().map(Self)
+            }
+        }
+
         impl CheckGcd for $name {
             fn gcd(&self, rhs: &Self) -> Option<Self> {
                 self.abs()?.gcd_sign_unsafe(&rhs.abs()?)
@@ -519,6 +656,18 @@ macro_rules! defineFromInt_ {
     }
 }
 
+macro_rules! defineWiden {
+    ($($typ:ident => $wide:ident);*) => {
+        paste!{
+            $(
+                impl Widen for [<Check$typ:camel>] {
+                    type Wide = [<Check$wide:camel>];
+                }
+            )*
+        }
+    };
+}
+
 macro_rules! defineFrom {
     ($($typ:ident: ($($directly_from:ty),*));*) => {
         paste!{
@@ -553,6 +702,7 @@ macro_rules! defineTryFromCheck_ {
     ($($typ:ty, $name:ident: $(($from_name:ident, $from_typ:ty)),*);*) => {
         $(
             $(
+                #[allow(clippy::infallible_try_from)]
                 impl TryFrom<$from_name> for $name {
                     type Error = <$typ as TryFrom<$from_typ>>::Error;
 
@@ -582,6 +732,7 @@ macro_rules! defineTryFromInt_ {
     ($($typ:ty, $name:ident: $($from_typ:ty),*);*) => {
         $(
             $(
+                #[allow(clippy::infallible_try_from)]
                 impl TryFrom<$from_typ> for $name {
                     type Error = <$typ as TryFrom<$from_typ>>::Error;
 
@@ -638,18 +789,18 @@ defineFromInt! {
 }
 
 defineFrom! {
-    u8: (std::num::NonZeroU8, bool);
-    i8: (std::num::NonZeroI8, bool);
-    u16: (std::num::NonZeroU16, bool);
-    i16: (std::num::NonZeroI16, bool);
-    u32: (std::num::NonZeroU32, bool, std::net::Ipv4Addr, char);
-    i32: (std::num::NonZeroI32, bool);
-    u64: (std::num::NonZeroU64, bool, char);
-    i64: (std::num::NonZeroI64, bool);
-    u128: (std::num::NonZeroU128, bool, std::net::Ipv6Addr, char);
-    i128: (std::num::NonZeroI128, bool);
-    usize: (std::num::NonZeroUsize, bool);
-    isize: (std::num::NonZeroIsize, bool)
+    u8: (core::num::NonZeroU8, bool);
+    i8: (core::num::NonZeroI8, bool);
+    u16: (core::num::NonZeroU16, bool);
+    i16: (core::num::NonZeroI16, bool);
+    u32: (core::num::NonZeroU32, bool, core::net::Ipv4Addr, char);
+    i32: (core::num::NonZeroI32, bool);
+    u64: (core::num::NonZeroU64, bool, char);
+    i64: (core::num::NonZeroI64, bool);
+    u128: (core::num::NonZeroU128, bool, core::net::Ipv6Addr, char);
+    i128: (core::num::NonZeroI128, bool);
+    usize: (core::num::NonZeroUsize, bool);
+    isize: (core::num::NonZeroIsize, bool)
 }
 
 defineTryFromInt! {
@@ -686,3 +837,48 @@ defineFromSelf_typ! {
     u8; u16; u32; u64; u128; usize;
     i8; i16; i32; i64; i128; isize
 }
+
+defineWiden! {
+    u8 => u16; u16 => u32; u32 => u64; u64 => u128;
+    i8 => i16; i16 => i32; i32 => i64; i64 => i128
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn pow_returns_none_on_overflow() {
+        // the inherent `pow` takes `CheckU32` and shadows the `u32` trait impl
+        assert_eq!(Pow::pow(CheckI32::new(-3), 3u32), Some(CheckI32::new(-27)));
+        assert_eq!(
+            CheckI32::new(2).pow(CheckU32::new(30)),
+            Some(CheckI32::new(1 << 30))
+        );
+        assert_eq!(Pow::pow(CheckI32::new(2), 31u32), None);
+        assert_eq!(Pow::pow(CheckU8::new(0), 0u32), Some(CheckU8::new(1)));
+    }
+
+    #[cfg(feature = "num-rational")]
+    #[test]
+    fn ratio_round_trip() {
+        use num_rational::Ratio;
+
+        let val = CheckRdc::<CheckI32>::new_raw(CheckI32::new(6), CheckI32::new(-8));
+        let ratio = Ratio::<i32>::try_from(val.clone()).unwrap();
+
+        assert_eq!((*ratio.numer(), *ratio.denom()), (-3, 4));
+        assert_eq!(
+            CheckRdc::<CheckI32>::try_from(ratio).map(|val| val.into_parts()),
+            Ok((CheckI32::new(-3), CheckI32::new(4)))
+        );
+
+        let min = CheckRdc::<CheckI32>::new_raw(CheckI32::new(i32::MIN), CheckI32::new(-2));
+        assert_eq!(Ratio::<i32>::try_from(min), Err(ArithError::GcdOverflow));
+
+        assert_eq!(
+            CheckRdc::<CheckU8>::try_from(Ratio::new_raw(1u8, 0)).err(),
+            Some(ArithError::ZeroDenominator)
+        );
+    }
+}
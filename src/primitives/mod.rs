@@ -1 +1,2 @@
+pub mod adapters;
 pub mod check_int;
@@ -1,17 +1,59 @@
+pub use checked_reducible::{
+    farey, horner, mean, solve2x2, sum_reduced, variance, RationalAccumulator,
+};
+
 pub mod checked_reducible {
-    use crate::{CheckGcd, Checked, One, Zero};
+    use crate::{CheckGcd, Checked, ISqrt, Inv, One, Pow, Signed, Widen, Zero};
 
-    use std::{
+    use core::{
         fmt,
-        ops::{Add, Div, Mul, Neg, Sub},
+        ops::{Add, Div, Mul, Neg, Rem, Sub},
     };
 
+    /// Fraction whose operators return `None` instead of overflowing.
+    ///
+    /// The denominator is kept positive, so the sign lives in the numerator. The only
+    /// exception is a fraction built with `new_raw`; `simplify` restores the invariant
+    /// for it when possible.
+    ///
+    /// With an unsigned element type such as `CheckU64` the fraction is nonnegative-only:
+    /// a subtraction whose result would be negative underflows and returns `None`,
+    /// so `None` from `-` means "would be negative" as well as "too large"
     #[derive(Debug, Clone)]
     pub struct CheckRdc<T: CheckGcd + Zero + One + PartialEq> {
         num: T,
         denom: T,
     }
 
+    /// Failure of a checked operation on the parts of a `CheckRdc`
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    pub enum ArithError {
+        /// The gcd of the parts could not be computed
+        GcdOverflow,
+        /// A checked division of a part returned `None`
+        DivOverflow,
+        /// The denominator is zero
+        ZeroDenominator,
+        /// A multiplication of fractions returned `None`
+        MulOverflow,
+        /// An addition of fractions returned `None`
+        AddOverflow,
+    }
+
+    impl fmt::Display for ArithError {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            match self {
+                ArithError::GcdOverflow => write!(f, "gcd of the fraction parts overflowed"),
+                ArithError::DivOverflow => write!(f, "division of a fraction part overflowed"),
+                ArithError::ZeroDenominator => write!(f, "zero denominator"),
+                ArithError::MulOverflow => write!(f, "multiplication of fractions overflowed"),
+                ArithError::AddOverflow => write!(f, "addition of fractions overflowed"),
+            }
+        }
+    }
+
+    impl core::error::Error for ArithError {}
+
     impl<T: CheckGcd + Zero + One + PartialEq> CheckRdc<T>
     where
         for<'a> &'a T: Div<&'a T, Output = Option<T>>,
@@ -28,6 +70,33 @@ pub mod checked_reducible {
             res
         }
 
+        /// Like `new`, but reports a zero denominator or a failed reduction instead of panicking
+        pub fn try_new(num: T, denom: T) -> Result<CheckRdc<T>, ArithError> {
+            if denom.is_zero() {
+                return Err(ArithError::ZeroDenominator);
+            }
+
+            let mut res = CheckRdc { num, denom };
+            res.checked_simplify()?;
+
+            Ok(res)
+        }
+
+        /// Like `new`, but keeps `num` and `denom` as given instead of reducing them.
+        /// The operators work on unreduced fractions, but they are more likely to overflow,
+        /// so call `simplify` once the exact parts are no longer needed
+        pub fn new_raw(num: T, denom: T) -> CheckRdc<T> {
+            if denom.is_zero() {
+                panic!("Zero denominator");
+            }
+
+            CheckRdc { num, denom }
+        }
+
+        pub fn is_reduced(&self) -> bool {
+            matches!(self.num().gcd(self.denom()), Some(gcd) if gcd.is_one())
+        }
+
         pub fn num(&self) -> &T {
             &self.num
         }
@@ -36,37 +105,299 @@ pub mod checked_reducible {
             &self.denom
         }
 
+        /// `(num, denom)` as stored. They are reduced for fractions made with `new`,
+        /// but arithmetic results may need `simplify` first
+        pub fn as_parts(&self) -> (&T, &T) {
+            (&self.num, &self.denom)
+        }
+
+        /// Same as `as_parts`, but consumes the fraction
+        pub fn into_parts(self) -> (T, T) {
+            (self.num, self.denom)
+        }
+
+        /// Reduces the fraction and moves its sign into the numerator, so the denominator
+        /// is positive afterwards. A zero fraction becomes exactly `0/1`.
+        /// Returns `None` if the gcd or the sign change overflows
         pub fn simplify(&mut self) -> Option<()> {
-            let gcd = self.num().gcd(self.denom())?;
+            self.checked_simplify().ok()
+        }
+
+        /// Reduced copy of `self`, see `simplify`
+        pub fn reduce(&self) -> Option<CheckRdc<T>>
+        where
+            T: Clone,
+        {
+            let mut res = self.clone();
+            res.simplify()?;
+
+            Some(res)
+        }
+
+        /// Same as `simplify`, but reports which step failed.
+        /// The fraction is left untouched on error
+        pub fn checked_simplify(&mut self) -> Result<(), ArithError> {
+            if self.num().is_zero() {
+                self.denom = T::ONE;
+
+                return Ok(());
+            }
+
+            let gcd = self
+                .num()
+                .gcd(self.denom())
+                .ok_or(ArithError::GcdOverflow)?;
+
+            let num = (self.num() / &gcd).ok_or(ArithError::DivOverflow)?;
+            let denom = (self.denom() / &gcd).ok_or(ArithError::DivOverflow)?;
+
+            let res = CheckRdc { num, denom }.checked_normalize_sign()?;
+
+            self.num = res.num;
+            self.denom = res.denom;
+
+            Ok(())
+        }
+
+        fn checked_normalize_sign(self) -> Result<CheckRdc<T>, ArithError> {
+            // gcd(x, x) is |x|, so this is the sign of the denominator
+            // without requiring an ordering on T
+            let abs_denom = self.denom.gcd(&self.denom).ok_or(ArithError::GcdOverflow)?;
+            let sign = (&self.denom / &abs_denom).ok_or(ArithError::DivOverflow)?;
+
+            if sign.is_one() {
+                return Ok(self);
+            }
+
+            Ok(CheckRdc {
+                num: (&self.num / &sign).ok_or(ArithError::DivOverflow)?,
+                denom: (&self.denom / &sign).ok_or(ArithError::DivOverflow)?,
+            })
+        }
+
+        /// Moves the sign of the denominator to the numerator.
+        /// Returns `None` if the numerator can't be negated
+        fn normalize_sign(self) -> Option<CheckRdc<T>> {
+            self.checked_normalize_sign().ok()
+        }
+    }
+
+    fn lcm<T: CheckGcd>(lhs: &T, rhs: &T) -> Option<T>
+    where
+        for<'a> &'a T: Div<&'a T, Output = Option<T>> + Mul<&'a T, Output = Option<T>>,
+    {
+        let gcd = lhs.gcd(rhs)?;
+
+        &(lhs / &gcd)? * rhs
+    }
+
+    impl<T: CheckGcd + Zero + One + PartialEq + Clone> CheckRdc<T>
+    where
+        for<'a> &'a T: Div<&'a T, Output = Option<T>> + Mul<&'a T, Output = Option<T>>,
+    {
+        /// `gcd(a/b, c/d) = gcd(a, c)/lcm(b, d)`. The result is reduced
+        pub fn gcd(&self, other: &Self) -> Option<CheckRdc<T>> {
+            let mut lhs = self.clone();
+            lhs.simplify()?;
+
+            let mut rhs = other.clone();
+            rhs.simplify()?;
+
+            let num = lhs.num().gcd(rhs.num())?;
+            let denom = lcm::<T>(lhs.denom(), rhs.denom())?;
+
+            Some(CheckRdc { num, denom })
+        }
+
+        /// `lcm(a/b, c/d) = lcm(a, c)/gcd(b, d)`. The result is reduced
+        pub fn lcm(&self, other: &Self) -> Option<CheckRdc<T>> {
+            let mut lhs = self.clone();
+            lhs.simplify()?;
+
+            let mut rhs = other.clone();
+            rhs.simplify()?;
+
+            let num = lcm::<T>(lhs.num(), rhs.num())?;
+            let denom = lhs.denom().gcd(rhs.denom())?;
+
+            Some(CheckRdc { num, denom })
+        }
+    }
+
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    pub enum FromF64Error {
+        NotFinite,
+        Overflow,
+    }
+
+    impl fmt::Display for FromF64Error {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            match self {
+                FromF64Error::NotFinite => write!(f, "value is NaN or infinite"),
+                FromF64Error::Overflow => write!(f, "exact fraction does not fit the element type"),
+            }
+        }
+    }
+
+    impl core::error::Error for FromF64Error {}
+
+    impl<T: CheckGcd + Zero + One + PartialEq + TryFrom<i64>> CheckRdc<T>
+    where
+        for<'a> &'a T: Mul<&'a T, Output = Option<T>>,
+    {
+        /// Builds the exact dyadic fraction `m * 2^e` equal to `value`, without any rounding
+        pub fn try_from_f64(value: f64) -> Result<CheckRdc<T>, FromF64Error> {
+            if !value.is_finite() {
+                return Err(FromF64Error::NotFinite);
+            }
+
+            if value == 0.0 {
+                return Ok(CheckRdc {
+                    num: T::ZERO,
+                    denom: T::ONE,
+                });
+            }
+
+            let bits = value.to_bits();
+            let biased_exp = ((bits >> 52) & 0x7ff) as i32;
+            let fraction = bits & ((1 << 52) - 1);
+
+            let (mut mantissa, mut exp) = if biased_exp == 0 {
+                (fraction, -1074)
+            } else {
+                (fraction | (1 << 52), biased_exp - 1075)
+            };
+
+            let trailing = mantissa.trailing_zeros();
+            mantissa >>= trailing;
+            exp += trailing as i32;
+
+            let mut mantissa = mantissa as i64;
+            if value.is_sign_negative() {
+                mantissa = -mantissa;
+            }
+
+            let mantissa = T::try_from(mantissa).map_err(|_| FromF64Error::Overflow)?;
+
+            let two = T::try_from(2).map_err(|_| FromF64Error::Overflow)?;
+            let mut pow = T::ONE;
+            for _ in 0..exp.unsigned_abs() {
+                pow = (&pow * &two).ok_or(FromF64Error::Overflow)?;
+            }
+
+            if exp >= 0 {
+                Ok(CheckRdc {
+                    num: (&mantissa * &pow).ok_or(FromF64Error::Overflow)?,
+                    denom: T::ONE,
+                })
+            } else {
+                Ok(CheckRdc {
+                    num: mantissa,
+                    denom: pow,
+                })
+            }
+        }
+    }
+
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    pub enum ToF64Error {
+        Inexact,
+        OutOfRange,
+    }
+
+    impl fmt::Display for ToF64Error {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            match self {
+                ToF64Error::Inexact => write!(
+                    f,
+                    "numerator or denominator is not exactly representable as f64"
+                ),
+                ToF64Error::OutOfRange => write!(f, "fraction is outside the range of f64"),
+            }
+        }
+    }
+
+    impl core::error::Error for ToF64Error {}
+
+    /// Succeeds only when both stored parts convert to `f64` exactly, so the single rounding step
+    /// is the final (correctly rounded) division. Reduce the fraction first to give large parts a chance
+    impl<T: CheckGcd + Zero + One + PartialEq + Clone> TryFrom<&CheckRdc<T>> for f64
+    where
+        f64: TryFrom<T>,
+    {
+        type Error = ToF64Error;
 
-            self.num = (self.num() / &gcd).expect("Never fails");
+        fn try_from(value: &CheckRdc<T>) -> Result<f64, Self::Error> {
+            let num = f64::try_from(value.num.clone()).map_err(|_| ToF64Error::Inexact)?;
+            let denom = f64::try_from(value.denom.clone()).map_err(|_| ToF64Error::Inexact)?;
 
-            self.denom = (self.denom() / &gcd).expect("Never fails");
+            let res = num / denom;
+
+            if res.is_infinite() || (res == 0.0 && num != 0.0) {
+                return Err(ToF64Error::OutOfRange);
+            }
 
-            Some(())
+            Ok(res)
         }
     }
 
+    /// Builds a reduced `CheckRdc`:
+    /// - `chrdc!(num, denom)` from the two parts
+    /// - `chrdc!((num, denom))` from a tuple of the parts
+    /// - `chrdc!(ty: T)` as `1/1` with element type `T`
     #[macro_export]
     macro_rules! chrdc {
-        ($num:expr, $denom:expr) => {
-            CheckRdc::new($num, $denom)
+        (ty: $typ:ty) => {
+            $crate::reducible::checked_reducible::CheckRdc::<$typ>::new(
+                <$typ as $crate::One>::non_zero(),
+                <$typ as $crate::One>::non_zero(),
+            )
         };
 
-        ($typ:ty) => {
-            CheckRdc::new(<$typ>::non_zero(), <$typ>::non_zero())
+        ($num:expr, $denom:expr $(,)?) => {
+            $crate::reducible::checked_reducible::IntoCheckRdc::into_check_rdc($num, $denom)
         };
 
-        ($num_denom:expr) => {
-            CheckRdc::new($num_denom.0, $num_denom.1)
-        };
+        ($num_denom:expr) => {{
+            let (num, denom) = $num_denom;
+
+            $crate::reducible::checked_reducible::IntoCheckRdc::into_check_rdc(num, denom)
+        }};
+    }
+
+    // Used by `chrdc!`. Calling `CheckRdc::new` before the element type is known makes
+    // the compiler overflow on the reference operator bounds, while a trait method
+    // picks the element type from its receiver first
+    #[doc(hidden)]
+    pub trait IntoCheckRdc: CheckGcd + Zero + One + PartialEq {
+        fn into_check_rdc(self, denom: Self) -> CheckRdc<Self>;
+    }
+
+    impl<T: CheckGcd + Zero + One + PartialEq> IntoCheckRdc for T
+    where
+        for<'a> &'a T: Div<&'a T, Output = Option<T>>,
+    {
+        fn into_check_rdc(self, denom: Self) -> CheckRdc<Self> {
+            CheckRdc::<T>::new(self, denom)
+        }
+    }
+
+    /// Same as `new`, so it panics on a zero denominator. Use `try_new` to avoid that
+    impl<T: CheckGcd + Zero + One + PartialEq> From<(T, T)> for CheckRdc<T>
+    where
+        for<'a> &'a T: Div<&'a T, Output = Option<T>>,
+    {
+        fn from((num, denom): (T, T)) -> Self {
+            Self::new(num, denom)
+        }
     }
 
-    impl<T: CheckGcd + Zero + One + PartialEq> std::default::Default for CheckRdc<T> {
+    /// `0/1`, the same as `Zero::ZERO`
+    impl<T: CheckGcd + Zero + One + PartialEq> core::default::Default for CheckRdc<T> {
         fn default() -> Self {
             CheckRdc {
-                num: T::non_zero(),
-                denom: T::non_zero(),
+                num: T::ZERO,
+                denom: T::ONE,
             }
         }
     }
@@ -98,25 +429,19 @@ pub mod checked_reducible {
                 return lhs == rhs;
             }
 
-            let mut new_self = self.clone();
-            new_self.simplify();
+            if self.num == other.num && self.denom == other.denom {
+                return true;
+            }
 
+            // reduced fractions with a positive denominator are equal only if their parts are.
+            // A fraction that fails to reduce has no such form in T, so it can't equal one that does
+            let mut new_self = self.clone();
             let mut other = other.clone();
-            other.simplify();
-
-            let denom_gcd = new_self.denom().gcd(other.denom()).unwrap();
 
-            let lhs = match new_self.num() * &((other.denom() / &denom_gcd).expect("Never fails")) {
-                Some(val) => val,
-                None => panic!("Failed to compare fractions"),
-            };
-
-            let rhs = match other.num() * &((new_self.denom() / &denom_gcd).expect("Never fails")) {
-                Some(val) => val,
-                None => panic!("Failed to compare fractions"),
-            };
-
-            lhs == rhs
+            match (new_self.simplify(), other.simplify()) {
+                (Some(()), Some(())) => new_self.num == other.num && new_self.denom == other.denom,
+                _ => false,
+            }
         }
     }
 
@@ -125,11 +450,16 @@ pub mod checked_reducible {
     {
     }
 
+    /// Compares the cross products when they fit. Otherwise the continued fraction
+    /// expansions are compared, which never overflows but needs a checked `Rem` on the
+    /// element type, like the one every `Check*` integer has
     impl<T: CheckGcd + Zero + One + PartialEq + PartialOrd + Clone> PartialOrd for CheckRdc<T>
     where
-        for<'a> &'a T: Mul<&'a T, Output = Option<T>> + Div<&'a T, Output = Option<T>>,
+        for<'a> &'a T: Mul<&'a T, Output = Option<T>>
+            + Div<&'a T, Output = Option<T>>
+            + Rem<&'a T, Output = Option<T>>,
     {
-        fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        fn partial_cmp(&self, other: &Self) -> Option<core::cmp::Ordering> {
             let mut overflowed = false;
 
             let lhs = match &self.num * &other.denom {
@@ -149,22 +479,259 @@ pub mod checked_reducible {
             };
 
             if !overflowed {
-                return lhs.partial_cmp(&rhs);
+                let ord = lhs.partial_cmp(&rhs)?;
+
+                // multiplying by a negative denominator from `new_raw` flips the order
+                return Some(if (self.denom < T::ZERO) != (other.denom < T::ZERO) {
+                    ord.reverse()
+                } else {
+                    ord
+                });
             }
 
-            let mut new_self = self.clone();
-            new_self.simplify();
+            use core::cmp::Ordering;
 
-            let mut other = other.clone();
-            other.simplify();
+            let sign = |val: &Self| {
+                let num = val.num().partial_cmp(&T::ZERO)?;
+
+                match val.denom().partial_cmp(&T::ZERO)? {
+                    Ordering::Less => Some(num.reverse()),
+                    _ => Some(num),
+                }
+            };
+
+            if self.num == other.num && self.denom == other.denom {
+                return Some(Ordering::Equal);
+            }
+
+            let (lhs_sign, rhs_sign) = (sign(self)?, sign(other)?);
+
+            if lhs_sign != rhs_sign || lhs_sign == Ordering::Equal {
+                return Some(lhs_sign.cmp(&rhs_sign));
+            }
+
+            let negative = lhs_sign == Ordering::Less;
+
+            // Compare the continued fraction expansions term by term. Every step only
+            // divides parts that are already stored, so nothing can overflow
+            let (mut a, mut b) = (self.num.clone(), self.denom.clone());
+            let (mut c, mut d) = (other.num.clone(), other.denom.clone());
+            let mut reversed = false;
+
+            loop {
+                let lhs = (&a / &b)?;
+                let rhs = (&c / &d)?;
+
+                let ord = if lhs != rhs {
+                    lhs.partial_cmp(&rhs)?
+                } else {
+                    let lhs_rem = (&a % &b)?;
+                    let rhs_rem = (&c % &d)?;
+
+                    // the fractional part left after truncation has the sign of the value
+                    match (lhs_rem.is_zero(), rhs_rem.is_zero()) {
+                        (true, true) => Ordering::Equal,
+                        (true, false) if negative => Ordering::Greater,
+                        (true, false) => Ordering::Less,
+                        (false, true) if negative => Ordering::Less,
+                        (false, true) => Ordering::Greater,
+                        (false, false) => {
+                            // both fractional parts have the same sign, so taking
+                            // their reciprocals reverses the order
+                            (a, b) = (b, lhs_rem);
+                            (c, d) = (d, rhs_rem);
+                            reversed = !reversed;
+
+                            continue;
+                        }
+                    }
+                };
+
+                return Some(if reversed { ord.reverse() } else { ord });
+            }
+        }
+    }
+
+    impl<T: CheckGcd + Zero + One + PartialEq + PartialOrd + Clone> CheckRdc<T>
+    where
+        for<'a> &'a T: Add<&'a T, Output = Option<T>>
+            + Sub<&'a T, Output = Option<T>>
+            + Mul<&'a T, Output = Option<T>>
+            + Div<&'a T, Output = Option<T>>
+            + Rem<&'a T, Output = Option<T>>,
+    {
+        /// Closest fraction with a denominator of at most `max_denom`, found from the
+        /// continued fraction convergents. Fractions that already fit are only reduced.
+        /// Panics if `max_denom < 1`. Returns `None` on overflow
+        pub fn clamp_denominator(&self, max_denom: T) -> Option<CheckRdc<T>> {
+            if max_denom < T::ONE {
+                panic!("Maximal denominator must be positive");
+            }
+
+            let value = self.reduce()?;
+
+            if value.denom <= max_denom {
+                return Some(value);
+            }
+
+            // p1/q1 is the last convergent, p0/q0 the one before it
+            let (mut p0, mut q0, mut p1, mut q1) = (T::ZERO, T::ONE, T::ONE, T::ZERO);
+            let (mut n, mut d) = (value.num.clone(), value.denom.clone());
+
+            while !d.is_zero() {
+                let mut a = (&n / &d)?;
+                let mut rem = (&n % &d)?;
+
+                // continued fraction terms use floor division
+                if rem < T::ZERO {
+                    a = (&a - &T::ONE)?;
+                    rem = (&rem + &d)?;
+                }
+
+                let q2 = (&q0 + &(&a * &q1)?)?;
+
+                if q2 > max_denom {
+                    break;
+                }
+
+                let p2 = (&p0 + &(&a * &p1)?)?;
+
+                (p0, q0, p1, q1) = (p1, q1, p2, q2);
+                (n, d) = (d, rem);
+            }
+
+            // the best approximation is either the last convergent or
+            // the largest semiconvergent that still fits
+            let k = (&(&max_denom - &q0)? / &q1)?;
+            let semi = CheckRdc {
+                num: (&p0 + &(&k * &p1)?)?,
+                denom: (&q0 + &(&k * &q1)?)?,
+            };
+            let conv = CheckRdc { num: p1, denom: q1 };
+
+            // `value` lies between the two candidates
+            let mid = semi.midpoint(&conv)?;
+            let conv_closer = if conv > semi {
+                value >= mid
+            } else {
+                value <= mid
+            };
+
+            let mut res = if conv_closer { conv } else { semi };
+            res.simplify()?;
+
+            Some(res)
+        }
+
+        /// Nearest integer multiple of `step`, computed exactly. Ties round away from zero.
+        /// Panics if `step` is zero. Returns `None` on overflow
+        pub fn round_to_multiple(&self, step: &CheckRdc<T>) -> Option<CheckRdc<T>> {
+            if step.num.is_zero() {
+                panic!("Rounding to a multiple of zero");
+            }
+
+            // the quotient comes with a positive denominator
+            let quot = (self / step)?;
+
+            let mut k = (&quot.num / &quot.denom)?;
+            let rem = (&quot.num % &quot.denom)?;
+            let rem = if rem < T::ZERO {
+                (&T::ZERO - &rem)?
+            } else {
+                rem
+            };
+
+            // rounds up when the fractional part is at least one half
+            if rem >= (&quot.denom - &rem)? {
+                k = if quot.num < T::ZERO {
+                    (&k - &T::ONE)?
+                } else {
+                    (&k + &T::ONE)?
+                };
+            }
 
-            let denom_gcd = self.denom().gcd(other.denom())?;
+            step.scale(&k)
+        }
+    }
+
+    impl<T: CheckGcd + Zero + One + PartialEq + PartialOrd> CheckRdc<T> {
+        /// Checks only the numerator, relying on the positive denominator.
+        /// Use `Signed::is_negative` for fractions that may break the invariant
+        pub fn num_is_negative(&self) -> bool {
+            self.num < T::ZERO
+        }
+    }
+
+    /// Compares with `scalar/1`
+    impl<T: CheckGcd + Zero + One + PartialEq + Clone> PartialEq<T> for CheckRdc<T>
+    where
+        for<'a> &'a T: Mul<&'a T, Output = Option<T>> + Div<&'a T, Output = Option<T>>,
+    {
+        fn eq(&self, scalar: &T) -> bool {
+            if self.denom.is_one() {
+                return self.num == *scalar;
+            }
+
+            *self
+                == CheckRdc {
+                    num: scalar.clone(),
+                    denom: T::ONE,
+                }
+        }
+    }
+
+    /// Compares with `scalar/1`
+    impl<T: CheckGcd + Zero + One + PartialEq + PartialOrd + Clone> PartialOrd<T> for CheckRdc<T>
+    where
+        for<'a> &'a T: Mul<&'a T, Output = Option<T>>
+            + Div<&'a T, Output = Option<T>>
+            + Rem<&'a T, Output = Option<T>>,
+    {
+        fn partial_cmp(&self, scalar: &T) -> Option<core::cmp::Ordering> {
+            self.partial_cmp(&CheckRdc {
+                num: scalar.clone(),
+                denom: T::ONE,
+            })
+        }
+    }
+
+    impl<T: CheckGcd + Zero + One + PartialEq + PartialOrd + Clone> CheckRdc<T>
+    where
+        for<'a> &'a T: Mul<&'a T, Output = Option<T>>
+            + Div<&'a T, Output = Option<T>>
+            + Rem<&'a T, Output = Option<T>>,
+    {
+        /// Panics if `lo > hi`. If `self` can't be compared with a bound, that bound is ignored
+        pub fn clamp(self, lo: CheckRdc<T>, hi: CheckRdc<T>) -> CheckRdc<T> {
+            if lo > hi {
+                panic!("Invalid clamp bounds: lo > hi");
+            }
 
-            let lhs = (new_self.num() * &((other.denom() / &denom_gcd).expect("Never fails")))?;
+            if self < lo {
+                lo
+            } else if self > hi {
+                hi
+            } else {
+                self
+            }
+        }
 
-            let rhs = (other.num() * &((new_self.denom() / &denom_gcd).expect("Never fails")))?;
+        /// If the fractions can't be compared, `self` is returned
+        pub fn min(self, other: CheckRdc<T>) -> CheckRdc<T> {
+            if other < self {
+                other
+            } else {
+                self
+            }
+        }
 
-            lhs.partial_cmp(&rhs)
+        /// If the fractions can't be compared, `self` is returned
+        pub fn max(self, other: CheckRdc<T>) -> CheckRdc<T> {
+            if other > self {
+                other
+            } else {
+                self
+            }
         }
     }
 
@@ -203,11 +770,11 @@ pub mod checked_reducible {
             let s_num_r_denom_gcd = self.num().gcd(rhs.denom())?;
             let s_denom_r_num_gcd = self.denom().gcd(rhs.num())?;
 
-            self.num = (self.num() / &s_num_r_denom_gcd).expect("Never fails");
-            rhs.denom = (rhs.denom() / &s_num_r_denom_gcd).expect("Never fails");
+            self.num = (self.num() / &s_num_r_denom_gcd)?;
+            rhs.denom = (rhs.denom() / &s_num_r_denom_gcd)?;
 
-            self.denom = (self.denom() / &s_denom_r_num_gcd).expect("Never fails");
-            rhs.num = (rhs.num() / &s_denom_r_num_gcd).expect("Never fails");
+            self.denom = (self.denom() / &s_denom_r_num_gcd)?;
+            rhs.num = (rhs.num() / &s_denom_r_num_gcd)?;
 
             let num = (self.num() * rhs.num())?;
 
@@ -252,14 +819,14 @@ pub mod checked_reducible {
             new_self.simplify();
             rhs.simplify();
 
-            let s_num_r_denom_gcd = self.num().gcd(rhs.denom())?;
-            let s_denom_r_num_gcd = self.denom().gcd(rhs.num())?;
+            let s_num_r_denom_gcd = new_self.num().gcd(rhs.denom())?;
+            let s_denom_r_num_gcd = new_self.denom().gcd(rhs.num())?;
 
-            new_self.num = (self.num() / &s_num_r_denom_gcd).expect("Never fails");
-            rhs.denom = (rhs.denom() / &s_num_r_denom_gcd).expect("Never fails");
+            new_self.num = (new_self.num() / &s_num_r_denom_gcd)?;
+            rhs.denom = (rhs.denom() / &s_num_r_denom_gcd)?;
 
-            new_self.denom = (self.denom() / &s_denom_r_num_gcd).expect("Never fails");
-            rhs.num = (rhs.num() / &s_denom_r_num_gcd).expect("Never fails");
+            new_self.denom = (new_self.denom() / &s_denom_r_num_gcd)?;
+            rhs.num = (rhs.num() / &s_denom_r_num_gcd)?;
 
             let num = (new_self.num() * rhs.num())?;
 
@@ -286,10 +853,10 @@ pub mod checked_reducible {
                     let mut rhs = rhs.clone();
                     new_self.simplify();
 
-                    let s_denom_r_num_gcd = self.denom().gcd(&rhs)?;
+                    let s_denom_r_num_gcd = new_self.denom().gcd(&rhs)?;
 
-                    new_self.denom = (new_self.denom() / &s_denom_r_num_gcd).expect("Never fails");
-                    rhs = (&rhs / &s_denom_r_num_gcd).expect("Never failes");
+                    new_self.denom = (new_self.denom() / &s_denom_r_num_gcd)?;
+                    rhs = (&rhs / &s_denom_r_num_gcd)?;
 
                     let num = (new_self.num() * &rhs)?;
 
@@ -320,8 +887,8 @@ pub mod checked_reducible {
 
                     let s_denom_r_num_gcd = self.denom().gcd(&rhs)?;
 
-                    self.denom = (self.denom() / &s_denom_r_num_gcd).expect("Never fails");
-                    rhs = (&rhs / &s_denom_r_num_gcd).expect("Never failes");
+                    self.denom = (self.denom() / &s_denom_r_num_gcd)?;
+                    rhs = (&rhs / &s_denom_r_num_gcd)?;
 
                     let num = (self.num() * &rhs)?;
 
@@ -346,56 +913,146 @@ pub mod checked_reducible {
             }
 
             match self.denom() * rhs {
-                Some(val) => Some(CheckRdc {
+                Some(val) => CheckRdc {
                     num: self.num.clone(),
                     denom: val,
-                }),
+                }
+                .normalize_sign(),
                 None => {
                     let mut new_self = self.clone();
                     let mut rhs = rhs.clone();
                     new_self.simplify();
 
-                    let s_num_r_num_gcd = self.num().gcd(&rhs)?;
+                    let s_num_r_num_gcd = new_self.num().gcd(&rhs)?;
 
-                    new_self.num = (new_self.num() / &s_num_r_num_gcd).expect("never fails");
-                    rhs = (&rhs / &s_num_r_num_gcd).expect("never failes");
+                    new_self.num = (new_self.num() / &s_num_r_num_gcd)?;
+                    rhs = (&rhs / &s_num_r_num_gcd)?;
 
                     let denom = (new_self.denom() * &rhs)?;
 
-                    Some(CheckRdc {
+                    CheckRdc {
                         num: new_self.num.clone(),
                         denom,
-                    })
+                    }
+                    .normalize_sign()
                 }
             }
         }
     }
 
-    impl<T: CheckGcd + Zero + One + PartialEq> Add<Self> for &mut CheckRdc<T>
+    impl<T: CheckGcd + Zero + One + PartialEq + Clone> CheckRdc<T>
     where
-        for<'a> &'a T: Div<&'a T, Output = Option<T>>
-            + Mul<&'a T, Output = Option<T>>
-            + Add<&'a T, Output = Option<T>>,
+        for<'a> &'a T: Div<&'a T, Output = Option<T>> + Mul<&'a T, Output = Option<T>>,
     {
-        type Output = Option<CheckRdc<T>>;
+        /// `self * k`, cancelling `k` against the denominator first.
+        /// `* &k` only does that after the plain product overflows, so its result
+        /// can keep a common factor, like `1/4 * 2 = 2/4` where `scale` gives `1/2`
+        pub fn scale(&self, k: &T) -> Option<CheckRdc<T>> {
+            if k.is_zero() {
+                return Some(CheckRdc {
+                    num: T::ZERO,
+                    denom: T::ONE,
+                });
+            }
 
-        fn add(self, rhs: Self) -> Self::Output {
-            let mut already_simplified = false;
+            let gcd = self.denom().gcd(k)?;
 
-            let mut denom_gcd;
+            Some(CheckRdc {
+                num: (self.num() * &(k / &gcd)?)?,
+                denom: (self.denom() / &gcd)?,
+            })
+        }
 
-            // HACK: here and a few lines later i declare a reference that'll be mainly used in calcualtions
-            // but when overflow occurs i change multipliers. So second variable
-            // is for storing those values and the first is for taking references to them
-            let mut self_mult = rhs.denom();
-            let mut self_mult_val;
+        /// `self / k`, cancelling `k` against the numerator first, see `scale`.
+        /// Panics if `k` is zero
+        pub fn div_int(&self, k: &T) -> Option<CheckRdc<T>> {
+            if k.is_zero() {
+                panic!("dividing by zero")
+            }
 
-            let mut rhs_mult = self.denom();
-            let mut rhs_mult_val;
+            if self.num().is_zero() {
+                return Some(self.clone());
+            }
 
-            let mut new_denom = match self.denom() * rhs.denom() {
-                Some(val) => val,
-                None => {
+            let gcd = self.num().gcd(k)?;
+
+            CheckRdc {
+                num: (self.num() / &gcd)?,
+                denom: (self.denom() * &(k / &gcd)?)?,
+            }
+            .normalize_sign()
+        }
+    }
+
+    impl<T: CheckGcd + Zero + One + PartialEq + Clone> Mul<T> for &CheckRdc<T>
+    where
+        for<'a> &'a T: Div<&'a T, Output = Option<T>> + Mul<&'a T, Output = Option<T>>,
+    {
+        type Output = Option<CheckRdc<T>>;
+
+        fn mul(self, rhs: T) -> Self::Output {
+            self * &rhs
+        }
+    }
+
+    impl<T: CheckGcd + Zero + One + PartialEq + Clone> Mul<T> for CheckRdc<T>
+    where
+        for<'a> &'a T: Div<&'a T, Output = Option<T>> + Mul<&'a T, Output = Option<T>>,
+    {
+        type Output = Option<CheckRdc<T>>;
+
+        fn mul(self, rhs: T) -> Self::Output {
+            &self * &rhs
+        }
+    }
+
+    impl<T: CheckGcd + Zero + One + PartialEq + Clone> Div<T> for &CheckRdc<T>
+    where
+        for<'a> &'a T: Div<&'a T, Output = Option<T>> + Mul<&'a T, Output = Option<T>>,
+    {
+        type Output = Option<CheckRdc<T>>;
+
+        fn div(self, rhs: T) -> Self::Output {
+            self / &rhs
+        }
+    }
+
+    impl<T: CheckGcd + Zero + One + PartialEq + Clone> Div<T> for CheckRdc<T>
+    where
+        for<'a> &'a T: Div<&'a T, Output = Option<T>> + Mul<&'a T, Output = Option<T>>,
+    {
+        type Output = Option<CheckRdc<T>>;
+
+        fn div(self, rhs: T) -> Self::Output {
+            &self / &rhs
+        }
+    }
+
+    impl<T: CheckGcd + Zero + One + PartialEq> Add<Self> for &mut CheckRdc<T>
+    where
+        for<'a> &'a T: Div<&'a T, Output = Option<T>>
+            + Mul<&'a T, Output = Option<T>>
+            + Add<&'a T, Output = Option<T>>,
+    {
+        type Output = Option<CheckRdc<T>>;
+
+        fn add(self, rhs: Self) -> Self::Output {
+            let mut already_simplified = false;
+
+            let mut denom_gcd;
+
+            // HACK: here and a few lines later i declare a reference that'll be mainly used in calcualtions
+            // but when overflow occurs i change multipliers. So second variable
+            // is for storing those values and the first is for taking references to them
+            let mut self_mult = rhs.denom();
+            let mut self_mult_val;
+
+            let mut rhs_mult = self.denom();
+            let mut rhs_mult_val;
+
+            let mut new_denom = match self.denom() * rhs.denom() {
+                Some(val) => val,
+                None => {
                     self.simplify();
                     rhs.simplify();
 
@@ -403,10 +1060,10 @@ pub mod checked_reducible {
 
                     denom_gcd = self.denom().gcd(rhs.denom())?;
 
-                    self_mult_val = (rhs.denom() / &denom_gcd).expect("Never fails");
+                    self_mult_val = (rhs.denom() / &denom_gcd)?;
                     self_mult = &self_mult_val;
 
-                    rhs_mult_val = (self.denom() / &denom_gcd).expect("Never fails");
+                    rhs_mult_val = (self.denom() / &denom_gcd)?;
                     rhs_mult = &rhs_mult_val;
 
                     (self.denom() * self_mult)?
@@ -427,10 +1084,10 @@ pub mod checked_reducible {
 
                     denom_gcd = self.denom().gcd(rhs.denom())?;
 
-                    self_mult_val = (rhs.denom() / &denom_gcd).expect("Never fails");
+                    self_mult_val = (rhs.denom() / &denom_gcd)?;
                     self_mult = &self_mult_val;
 
-                    rhs_mult_val = (self.denom() / &denom_gcd).expect("Never fails");
+                    rhs_mult_val = (self.denom() / &denom_gcd)?;
                     rhs_mult = &rhs_mult_val;
 
                     new_denom = (self.denom() * self_mult)?;
@@ -453,10 +1110,10 @@ pub mod checked_reducible {
 
                     denom_gcd = self.denom().gcd(rhs.denom())?;
 
-                    self_mult_val = (rhs.denom() / &denom_gcd).expect("Never fails");
+                    self_mult_val = (rhs.denom() / &denom_gcd)?;
                     self_mult = &self_mult_val;
 
-                    rhs_mult_val = (self.denom() / &denom_gcd).expect("Never fails");
+                    rhs_mult_val = (self.denom() / &denom_gcd)?;
                     rhs_mult = &rhs_mult_val;
 
                     new_denom = (self.denom() * self_mult)?;
@@ -477,10 +1134,10 @@ pub mod checked_reducible {
 
                     denom_gcd = self.denom().gcd(rhs.denom())?;
 
-                    self_mult_val = (rhs.denom() / &denom_gcd).expect("Never fails");
+                    self_mult_val = (rhs.denom() / &denom_gcd)?;
                     self_mult = &self_mult_val;
 
-                    rhs_mult_val = (self.denom() / &denom_gcd).expect("Never fails");
+                    rhs_mult_val = (self.denom() / &denom_gcd)?;
                     rhs_mult = &rhs_mult_val;
 
                     new_denom = (self.denom() * self_mult)?;
@@ -512,6 +1169,134 @@ pub mod checked_reducible {
         }
     }
 
+    impl<T: CheckGcd + Zero + One + PartialEq + Clone> Add<&T> for &CheckRdc<T>
+    where
+        for<'a> &'a T: Div<&'a T, Output = Option<T>>
+            + Mul<&'a T, Output = Option<T>>
+            + Add<&'a T, Output = Option<T>>,
+    {
+        type Output = Option<CheckRdc<T>>;
+
+        fn add(self, rhs: &T) -> Self::Output {
+            let rhs = CheckRdc {
+                num: rhs.clone(),
+                denom: T::ONE,
+            };
+
+            self + &rhs
+        }
+    }
+
+    impl<T: CheckGcd + Zero + One + PartialEq + Clone> CheckRdc<T>
+    where
+        for<'a> &'a T: Div<&'a T, Output = Option<T>>
+            + Mul<&'a T, Output = Option<T>>
+            + Add<&'a T, Output = Option<T>>,
+    {
+        /// Exact `(self + other) / 2`
+        pub fn midpoint(&self, other: &Self) -> Option<CheckRdc<T>> {
+            let two = (&T::ONE + &T::ONE)?;
+
+            &(self + other)? / &two
+        }
+
+        /// `self * mul + add`. The product is reduced before the addition, and if it
+        /// still overflows, `add` is folded into one factor first (`mul * (self + add / mul)`),
+        /// so this can succeed where the separate operators return `None`
+        pub fn mul_add(&self, mul: &Self, add: &Self) -> Option<CheckRdc<T>> {
+            if let Some(mut prod) = self * mul {
+                if let Some(res) = prod.simplify().and_then(|_| &prod + add) {
+                    return Some(res);
+                }
+            }
+
+            let fold_into = |factor: &Self, other: &Self| {
+                if factor.num().is_zero() {
+                    return None;
+                }
+
+                &(other + &(add / factor)?)? * factor
+            };
+
+            fold_into(mul, self).or_else(|| fold_into(self, mul))
+        }
+    }
+
+    impl<T: CheckGcd + Zero + One + PartialEq + Clone> CheckRdc<T>
+    where
+        for<'a> &'a T: Div<&'a T, Output = Option<T>> + Mul<&'a T, Output = Option<T>>,
+    {
+        /// Scales both numerators to `lcm(self.denom, rhs.denom)`
+        fn lcm_parts(&self, rhs: &Self) -> Option<(T, T, T)> {
+            let denom = lcm::<T>(self.denom(), rhs.denom())?;
+
+            let lhs_num = (self.num() * &(&denom / self.denom())?)?;
+            let rhs_num = (rhs.num() * &(&denom / rhs.denom())?)?;
+
+            Some((lhs_num, rhs_num, denom))
+        }
+
+        /// Like `+`, but brings both fractions to the lcm of the denominators
+        /// instead of their product, so shared factors never enlarge the parts
+        pub fn add_lcm(&self, rhs: &Self) -> Option<CheckRdc<T>>
+        where
+            for<'a> &'a T: Add<&'a T, Output = Option<T>>,
+        {
+            let (lhs_num, rhs_num, denom) = self.lcm_parts(rhs)?;
+
+            Some(CheckRdc {
+                num: (&lhs_num + &rhs_num)?,
+                denom,
+            })
+        }
+
+        /// Like `-`, but with the lcm of the denominators, see `add_lcm`
+        pub fn sub_lcm(&self, rhs: &Self) -> Option<CheckRdc<T>>
+        where
+            for<'a> &'a T: Sub<&'a T, Output = Option<T>>,
+        {
+            let (lhs_num, rhs_num, denom) = self.lcm_parts(rhs)?;
+
+            Some(CheckRdc {
+                num: (&lhs_num - &rhs_num)?,
+                denom,
+            })
+        }
+    }
+
+    impl<T: CheckGcd + Zero + One + PartialEq + Clone + Widen> CheckRdc<T>
+    where
+        T::Wide: CheckGcd + Zero + One + PartialEq,
+    {
+        /// The same fraction over the wider element type, parts copied as they are
+        pub fn widen(&self) -> CheckRdc<T::Wide> {
+            CheckRdc {
+                num: self.num.clone().into(),
+                denom: self.denom.clone().into(),
+            }
+        }
+    }
+
+    impl<T: CheckGcd + Zero + One + PartialEq + Clone + Widen> CheckRdc<T>
+    where
+        T::Wide: CheckGcd + Zero + One + PartialEq + Clone,
+        for<'a> &'a T: Add<&'a T, Output = Option<T>>
+            + Mul<&'a T, Output = Option<T>>
+            + Div<&'a T, Output = Option<T>>,
+        for<'a> &'a T::Wide: Add<&'a T::Wide, Output = Option<T::Wide>>
+            + Mul<&'a T::Wide, Output = Option<T::Wide>>
+            + Div<&'a T::Wide, Output = Option<T::Wide>>,
+    {
+        /// `self + other`, retried over `T::Wide` if it overflows in `T`.
+        /// Returns `None` only if the wide addition overflows as well
+        pub fn add_widening(&self, other: &Self) -> Option<CheckRdc<T::Wide>> {
+            match self + other {
+                Some(res) => Some(res.widen()),
+                None => &self.widen() + &other.widen(),
+            }
+        }
+    }
+
     impl<T: CheckGcd + Zero + One + PartialEq> Sub<Self> for &mut CheckRdc<T>
     where
         for<'a> &'a T: Div<&'a T, Output = Option<T>>
@@ -544,10 +1329,10 @@ pub mod checked_reducible {
 
                     denom_gcd = self.denom().gcd(rhs.denom())?;
 
-                    self_mult_val = (rhs.denom() / &denom_gcd).expect("Never fails");
+                    self_mult_val = (rhs.denom() / &denom_gcd)?;
                     self_mult = &self_mult_val;
 
-                    rhs_mult_val = (self.denom() / &denom_gcd).expect("Never fails");
+                    rhs_mult_val = (self.denom() / &denom_gcd)?;
                     rhs_mult = &rhs_mult_val;
 
                     (self.denom() * self_mult)?
@@ -568,10 +1353,10 @@ pub mod checked_reducible {
 
                     denom_gcd = self.denom().gcd(rhs.denom())?;
 
-                    self_mult_val = (rhs.denom() / &denom_gcd).expect("Never fails");
+                    self_mult_val = (rhs.denom() / &denom_gcd)?;
                     self_mult = &self_mult_val;
 
-                    rhs_mult_val = (self.denom() / &denom_gcd).expect("Never fails");
+                    rhs_mult_val = (self.denom() / &denom_gcd)?;
                     rhs_mult = &rhs_mult_val;
 
                     new_denom = (self.denom() * self_mult)?;
@@ -594,10 +1379,10 @@ pub mod checked_reducible {
 
                     denom_gcd = self.denom().gcd(rhs.denom())?;
 
-                    self_mult_val = (rhs.denom() / &denom_gcd).expect("Never fails");
+                    self_mult_val = (rhs.denom() / &denom_gcd)?;
                     self_mult = &self_mult_val;
 
-                    rhs_mult_val = (self.denom() / &denom_gcd).expect("Never fails");
+                    rhs_mult_val = (self.denom() / &denom_gcd)?;
                     rhs_mult = &rhs_mult_val;
 
                     new_denom = (self.denom() * self_mult)?;
@@ -618,10 +1403,10 @@ pub mod checked_reducible {
 
                     denom_gcd = self.denom().gcd(rhs.denom())?;
 
-                    self_mult_val = (rhs.denom() / &denom_gcd).expect("Never fails");
+                    self_mult_val = (rhs.denom() / &denom_gcd)?;
                     self_mult = &self_mult_val;
 
-                    rhs_mult_val = (self.denom() / &denom_gcd).expect("Never fails");
+                    rhs_mult_val = (self.denom() / &denom_gcd)?;
                     rhs_mult = &rhs_mult_val;
 
                     new_denom = (self.denom() * self_mult)?;
@@ -653,6 +1438,39 @@ pub mod checked_reducible {
         }
     }
 
+    impl<T: CheckGcd + Zero + One + PartialEq + Clone> Sub<&T> for &CheckRdc<T>
+    where
+        for<'a> &'a T: Div<&'a T, Output = Option<T>>
+            + Mul<&'a T, Output = Option<T>>
+            + Sub<&'a T, Output = Option<T>>,
+    {
+        type Output = Option<CheckRdc<T>>;
+
+        fn sub(self, rhs: &T) -> Self::Output {
+            let rhs = CheckRdc {
+                num: rhs.clone(),
+                denom: T::ONE,
+            };
+
+            self - &rhs
+        }
+    }
+
+    impl<T: CheckGcd + Zero + One + PartialEq + Clone> CheckRdc<T>
+    where
+        for<'a> &'a T: Div<&'a T, Output = Option<T>>
+            + Mul<&'a T, Output = Option<T>>
+            + Add<&'a T, Output = Option<T>>
+            + Sub<&'a T, Output = Option<T>>,
+    {
+        /// Exact `self + (other - self) * t`, so there is no rounding drift for rational `t`
+        pub fn lerp(&self, other: &Self, t: &Self) -> Option<CheckRdc<T>> {
+            let diff = (other - self)?;
+
+            self + &(&diff * t)?
+        }
+    }
+
     impl<T: CheckGcd + Zero + One + PartialEq> Div<Self> for &mut CheckRdc<T>
     where
         for<'a> &'a T: Div<&'a T, Output = Option<T>> + Mul<&'a T, Output = Option<T>>,
@@ -683,7 +1501,7 @@ pub mod checked_reducible {
             };
 
             if !overflowed {
-                return Some(CheckRdc { num, denom });
+                return CheckRdc { num, denom }.normalize_sign();
             }
 
             self.simplify();
@@ -692,17 +1510,17 @@ pub mod checked_reducible {
             let s_num_r_num_gcd = self.num().gcd(rhs.num())?;
             let s_denom_r_denom_gcd = self.denom().gcd(rhs.denom())?;
 
-            self.num = (self.num() / &s_num_r_num_gcd).expect("Never fails");
-            rhs.num = (rhs.num() / &s_num_r_num_gcd).expect("Never fails");
+            self.num = (self.num() / &s_num_r_num_gcd)?;
+            rhs.num = (rhs.num() / &s_num_r_num_gcd)?;
 
-            self.denom = (self.denom() / &s_denom_r_denom_gcd).expect("Never fails");
-            rhs.denom = (rhs.denom() / &s_denom_r_denom_gcd).expect("Never fails");
+            self.denom = (self.denom() / &s_denom_r_denom_gcd)?;
+            rhs.denom = (rhs.denom() / &s_denom_r_denom_gcd)?;
 
             let num = (self.num() * rhs.denom())?;
 
             let denom = (self.denom() * rhs.num())?;
 
-            Some(CheckRdc { num, denom })
+            CheckRdc { num, denom }.normalize_sign()
         }
     }
 
@@ -736,7 +1554,7 @@ pub mod checked_reducible {
             };
 
             if !overflowed {
-                return Some(CheckRdc { num, denom });
+                return CheckRdc { num, denom }.normalize_sign();
             }
 
             let mut new_self = self.clone();
@@ -748,17 +1566,17 @@ pub mod checked_reducible {
             let s_num_r_num_gcd = new_self.num().gcd(rhs.num())?;
             let s_denom_r_denom_gcd = new_self.denom().gcd(rhs.denom())?;
 
-            new_self.num = (new_self.num() / &s_num_r_num_gcd).expect("Never fails");
-            rhs.num = (rhs.num() / &s_num_r_num_gcd).expect("Never fails");
+            new_self.num = (new_self.num() / &s_num_r_num_gcd)?;
+            rhs.num = (rhs.num() / &s_num_r_num_gcd)?;
 
-            new_self.denom = (new_self.denom() / &s_denom_r_denom_gcd).expect("Never fails");
-            rhs.denom = (rhs.denom() / &s_denom_r_denom_gcd).expect("Never fails");
+            new_self.denom = (new_self.denom() / &s_denom_r_denom_gcd)?;
+            rhs.denom = (rhs.denom() / &s_denom_r_denom_gcd)?;
 
             let num = (new_self.num() * rhs.denom())?;
 
             let denom = (new_self.denom() * rhs.num())?;
 
-            Some(CheckRdc { num, denom })
+            CheckRdc { num, denom }.normalize_sign()
         }
     }
 
@@ -792,102 +1610,614 @@ pub mod checked_reducible {
         }
     }
 
-    impl<T: CheckGcd + Zero + One + PartialEq> Zero for CheckRdc<T> {
-        const ZERO: Self = CheckRdc {
-            num: T::ZERO,
-            denom: T::ONE,
-        };
-    }
+    impl<T: CheckGcd + Zero + One + Clone + PartialEq> Neg for CheckRdc<T>
+    where
+        for<'a> &'a T: Div<&'a T, Output = Option<T>>
+            + Mul<&'a T, Output = Option<T>>
+            + Sub<&'a T, Output = Option<T>>,
+    {
+        type Output = Option<CheckRdc<T>>;
 
-    impl<T: CheckGcd + Zero + One + PartialEq> Checked for CheckRdc<T> {}
+        fn neg(self) -> Self::Output {
+            -&self
+        }
+    }
 
-    impl<T: CheckGcd + Zero + One + fmt::Display + PartialEq> fmt::Display for CheckRdc<T> {
-        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-            write!(f, "({})/({})", self.num, self.denom)
+    impl<T: CheckGcd + Zero + One + Clone + PartialEq> CheckRdc<T>
+    where
+        for<'a> &'a T: Neg<Output = Option<T>>,
+    {
+        /// Negation that doesn't go through subtraction, so it needs only `Neg` on the
+        /// element type. Returns `None` if the numerator can't be negated (like `MIN`)
+        pub fn negate(&self) -> Option<CheckRdc<T>> {
+            Some(CheckRdc {
+                num: (-&self.num)?,
+                denom: self.denom.clone(),
+            })
         }
     }
-}
 
-pub mod rdc {
-    use crate::{Gcd, One, Zero};
+    impl<T: CheckGcd + Zero + One + PartialEq + Clone + ISqrt> CheckRdc<T>
+    where
+        for<'a> &'a T: Div<&'a T, Output = Option<T>> + Mul<&'a T, Output = Option<T>>,
+    {
+        /// Exact square root. Returns `None` if the reduced numerator or denominator
+        /// is not a perfect square, so the root is irrational (or the fraction is negative)
+        pub fn checked_sqrt(&self) -> Option<CheckRdc<T>> {
+            let value = self.reduce()?;
 
-    use std::ops::{Add, Div, Mul, Neg, Sub};
+            let exact_root = |val: &T| {
+                let root = val.isqrt()?;
 
-    pub struct Rdc<T: Gcd + Div<T, Output = T> + Zero> {
-        num: T,
-        denom: T,
+                ((&root * &root)? == *val).then_some(root)
+            };
+
+            Some(CheckRdc {
+                num: exact_root(&value.num)?,
+                denom: exact_root(&value.denom)?,
+            })
+        }
     }
 
-    impl<T> Rdc<T>
+    impl<T: CheckGcd + Zero + One + PartialEq + Clone> Pow<u32> for &CheckRdc<T>
     where
-        T: Gcd + Div<T, Output = T> + Clone + Zero,
+        for<'a> &'a T: Div<&'a T, Output = Option<T>> + Mul<&'a T, Output = Option<T>>,
     {
-        pub fn num(&self) -> &T {
-            &self.num
-        }
+        type Output = Option<CheckRdc<T>>;
 
-        pub fn denom(&self) -> &T {
-            &self.denom
-        }
+        fn pow(self, mut exp: u32) -> Self::Output {
+            let mut res = CheckRdc {
+                num: T::ONE,
+                denom: T::ONE,
+            };
 
-        fn simplify(self) -> Self {
-            let gcd = self.num().gcd(self.denom());
+            let mut base = self.clone();
 
-            let num = self.num / gcd.clone();
-            let denom = self.denom / gcd;
+            // square-and-multiply, the base is not squared after the last bit
+            // so it can't overflow when the result itself fits
+            loop {
+                if exp & 1 == 1 {
+                    res = (&res * &base)?;
+                }
 
-            Rdc { num, denom }
-        }
+                exp >>= 1;
 
-        pub fn new(num: T, denom: T) -> Self {
-            let res = Rdc { num, denom };
+                if exp == 0 {
+                    return Some(res);
+                }
 
-            res.simplify()
+                base = (&base * &base)?;
+            }
         }
     }
 
-    impl<T> Mul for Rdc<T>
+    impl<T: CheckGcd + Zero + One + PartialEq + Clone> Inv for &CheckRdc<T>
     where
-        T: Gcd + Mul<T, Output = T> + Div<T, Output = T> + Zero + Clone,
+        for<'a> &'a T: Div<&'a T, Output = Option<T>>,
     {
-        type Output = Self;
+        type Output = Option<CheckRdc<T>>;
 
-        fn mul(self, rhs: Self) -> Self::Output {
-            Self::new(self.num * rhs.num, self.denom * rhs.denom)
+        /// Returns `None` when the numerator is zero
+        fn inv(self) -> Self::Output {
+            if self.num.is_zero() {
+                return None;
+            }
+
+            CheckRdc {
+                num: self.denom.clone(),
+                denom: self.num.clone(),
+            }
+            .normalize_sign()
         }
     }
 
-    impl<T> Div for Rdc<T>
-    where
-        T: Gcd + Div<T, Output = T> + Clone + Mul<T, Output = T> + Zero,
-    {
-        type Output = Self;
+    // The sign is taken from both parts, so it is correct whether or not
+    // the fraction was normalized
+    impl<T: CheckGcd + Zero + One + PartialEq + Signed> Signed for CheckRdc<T> {
+        fn abs(&self) -> Option<Self> {
+            Some(CheckRdc {
+                num: self.num.abs()?,
+                denom: self.denom.abs()?,
+            })
+        }
 
-        fn div(self, rhs: Self) -> Self::Output {
-            Self::new(self.num * rhs.denom, self.denom * rhs.num)
+        fn signum(&self) -> Self {
+            CheckRdc {
+                num: self.num.signum(),
+                denom: self.denom.signum(),
+            }
         }
-    }
 
-    impl<T> Add for Rdc<T>
-    where
-        for<'a> &'a T: Mul<&'a T, Output = T>,
-        T: Gcd + Div<T, Output = T> + Mul<T, Output = T> + Add<T, Output = T> + Clone + Zero,
-    {
-        type Output = Self;
+        fn is_positive(&self) -> bool {
+            !self.num.is_zero() && self.num.is_negative() == self.denom.is_negative()
+        }
 
-        fn add(self, rhs: Self) -> Self::Output {
-            let denom = self.denom() * rhs.denom();
+        fn is_negative(&self) -> bool {
+            !self.num.is_zero() && self.num.is_negative() != self.denom.is_negative()
+        }
+    }
 
-            let num = self.num * rhs.denom + self.denom * rhs.num;
+    impl<T: CheckGcd + Zero + One + PartialEq> Zero for CheckRdc<T> {
+        const ZERO: Self = CheckRdc {
+            num: T::ZERO,
+            denom: T::ONE,
+        };
 
-            Self::new(num, denom)
+        /// Checks only the numerator, so unreduced fractions like `0/7` need no
+        /// comparison with `0/1`
+        fn is_zero(&self) -> bool
+        where
+            Self: PartialEq + Sized,
+        {
+            self.num.is_zero()
         }
     }
 
-    impl<T> Sub for Rdc<T>
-    where
-        for<'a> &'a T: Mul<&'a T, Output = T>,
-        T: Gcd + Div<T, Output = T> + Mul<T, Output = T> + Sub<T, Output = T> + Clone + Zero,
+    impl<T: CheckGcd + Zero + One + PartialEq> Checked for CheckRdc<T> {}
+
+    /// Step in the Stern–Brocot tree
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+    pub enum Branch {
+        Left,
+        Right,
+    }
+
+    impl<T: CheckGcd + Zero + One + PartialEq + PartialOrd + Clone> CheckRdc<T>
+    where
+        for<'a> &'a T: Add<&'a T, Output = Option<T>>
+            + Sub<&'a T, Output = Option<T>>
+            + Div<&'a T, Output = Option<T>>,
+    {
+        /// Path from the root `1/1` to this fraction.
+        /// Returns `None` if the fraction is not positive
+        #[cfg(feature = "std")]
+        pub fn stern_brocot_path(&self) -> Option<Vec<Branch>> {
+            let mut reduced = self.clone();
+            reduced.simplify()?;
+
+            let CheckRdc {
+                num: mut p,
+                denom: mut q,
+            } = reduced;
+
+            if p <= T::ZERO {
+                return None;
+            }
+
+            let mut path = Vec::new();
+
+            while p != q {
+                if p < q {
+                    path.push(Branch::Left);
+                    q = (&q - &p)?;
+                } else {
+                    path.push(Branch::Right);
+                    p = (&p - &q)?;
+                }
+            }
+
+            Some(path)
+        }
+
+        /// Fraction at the end of `path`, starting from the root `1/1`.
+        /// Returns `None` on overflow
+        pub fn from_stern_brocot_path(path: &[Branch]) -> Option<CheckRdc<T>> {
+            // bounds of the current subtree as (num, denom), with 1/0 standing for infinity
+            let (mut lo, mut hi) = ((T::ZERO, T::ONE), (T::ONE, T::ZERO));
+            let mut mediant = (T::ONE, T::ONE);
+
+            for branch in path {
+                match branch {
+                    Branch::Left => hi = mediant,
+                    Branch::Right => lo = mediant,
+                }
+
+                mediant = ((&lo.0 + &hi.0)?, (&lo.1 + &hi.1)?);
+            }
+
+            Some(CheckRdc {
+                num: mediant.0,
+                denom: mediant.1,
+            })
+        }
+    }
+
+    /// Solves `a * [x, y] = b` exactly with Cramer's rule, where `a` is given by rows.
+    /// Returns `None` if `a` is singular or an intermediate value overflows
+    pub fn solve2x2<T: CheckGcd + Zero + One + PartialEq + Clone>(
+        a: [[CheckRdc<T>; 2]; 2],
+        b: [CheckRdc<T>; 2],
+    ) -> Option<[CheckRdc<T>; 2]>
+    where
+        for<'a> &'a T: Sub<&'a T, Output = Option<T>>
+            + Mul<&'a T, Output = Option<T>>
+            + Div<&'a T, Output = Option<T>>,
+    {
+        let [[a00, a01], [a10, a11]] = &a;
+        let [b0, b1] = &b;
+
+        let det = (&(a00 * a11)? - &(a01 * a10)?)?;
+
+        if det.num().is_zero() {
+            return None;
+        }
+
+        let x = (&(b0 * a11)? - &(a01 * b1)?)?;
+        let y = (&(a00 * b1)? - &(b0 * a10)?)?;
+
+        let mut x = (&x / &det)?;
+        let mut y = (&y / &det)?;
+        x.simplify()?;
+        y.simplify()?;
+
+        Some([x, y])
+    }
+
+    /// Evaluates the polynomial with `coeffs` (highest degree first) at `x` with Horner's method.
+    /// An empty slice is the zero polynomial. Returns `None` on overflow
+    pub fn horner<T: CheckGcd + Zero + One + PartialEq + Clone>(
+        coeffs: &[CheckRdc<T>],
+        x: &CheckRdc<T>,
+    ) -> Option<CheckRdc<T>>
+    where
+        for<'a> &'a T: Add<&'a T, Output = Option<T>>
+            + Mul<&'a T, Output = Option<T>>
+            + Div<&'a T, Output = Option<T>>,
+    {
+        let mut acc = CheckRdc::<T>::ZERO;
+
+        for coeff in coeffs {
+            acc = (&(&acc * x)? + coeff)?;
+            acc.simplify()?;
+        }
+
+        Some(acc)
+    }
+
+    /// Sums the fractions, reducing the running total after every term so its
+    /// denominator stays minimal. Returns `None` on overflow
+    pub fn sum_reduced<T: CheckGcd + Zero + One + PartialEq + Clone>(
+        iter: impl IntoIterator<Item = CheckRdc<T>>,
+    ) -> Option<CheckRdc<T>>
+    where
+        for<'a> &'a T: Add<&'a T, Output = Option<T>>
+            + Mul<&'a T, Output = Option<T>>
+            + Div<&'a T, Output = Option<T>>,
+    {
+        let mut acc = CheckRdc::<T>::ZERO;
+
+        for val in iter {
+            acc = (&acc + &val)?;
+            acc.simplify()?;
+        }
+
+        Some(acc)
+    }
+
+    /// Running sum of fractions, built for dot-product-like sums of products.
+    /// Every product and the total are reduced after each step, so the sum reaches
+    /// further before overflowing than a chain of operators would
+    #[derive(Debug, Clone)]
+    pub struct RationalAccumulator<T: CheckGcd + Zero + One + PartialEq> {
+        total: CheckRdc<T>,
+    }
+
+    impl<T: CheckGcd + Zero + One + PartialEq + Clone> RationalAccumulator<T>
+    where
+        for<'a> &'a T: Add<&'a T, Output = Option<T>>
+            + Mul<&'a T, Output = Option<T>>
+            + Div<&'a T, Output = Option<T>>,
+    {
+        pub fn new() -> Self {
+            RationalAccumulator {
+                total: CheckRdc::ZERO,
+            }
+        }
+
+        /// Adds `val` to the total. On error the total is left unchanged
+        pub fn add(&mut self, val: &CheckRdc<T>) -> Result<(), ArithError> {
+            let mut total = (&self.total + val).ok_or(ArithError::AddOverflow)?;
+            total.checked_simplify()?;
+
+            self.total = total;
+
+            Ok(())
+        }
+
+        /// Adds `a * b` to the total. On error the total is left unchanged
+        pub fn add_product(&mut self, a: &CheckRdc<T>, b: &CheckRdc<T>) -> Result<(), ArithError> {
+            let mut prod = (a * b).ok_or(ArithError::MulOverflow)?;
+            prod.checked_simplify()?;
+
+            self.add(&prod)
+        }
+
+        pub fn total(&self) -> &CheckRdc<T> {
+            &self.total
+        }
+
+        pub fn into_total(self) -> CheckRdc<T> {
+            self.total
+        }
+    }
+
+    impl<T: CheckGcd + Zero + One + PartialEq + Clone> Default for RationalAccumulator<T>
+    where
+        for<'a> &'a T: Add<&'a T, Output = Option<T>>
+            + Mul<&'a T, Output = Option<T>>
+            + Div<&'a T, Output = Option<T>>,
+    {
+        fn default() -> Self {
+            Self::new()
+        }
+    }
+
+    /// Exact arithmetic mean. Returns `None` for empty `data` or on overflow
+    pub fn mean<T: CheckGcd + Zero + One + PartialEq + Clone + TryFrom<usize>>(
+        data: &[CheckRdc<T>],
+    ) -> Option<CheckRdc<T>>
+    where
+        for<'a> &'a T: Add<&'a T, Output = Option<T>>
+            + Mul<&'a T, Output = Option<T>>
+            + Div<&'a T, Output = Option<T>>,
+    {
+        if data.is_empty() {
+            return None;
+        }
+
+        let mut acc = RationalAccumulator::<T>::new();
+
+        for val in data {
+            acc.add(val).ok()?;
+        }
+
+        acc.total().div_int(&T::try_from(data.len()).ok()?)
+    }
+
+    /// Exact population variance, the mean of the squared deviations from the mean.
+    /// Returns `None` for empty `data` or on overflow
+    pub fn variance<T: CheckGcd + Zero + One + PartialEq + Clone + TryFrom<usize>>(
+        data: &[CheckRdc<T>],
+    ) -> Option<CheckRdc<T>>
+    where
+        for<'a> &'a T: Add<&'a T, Output = Option<T>>
+            + Sub<&'a T, Output = Option<T>>
+            + Mul<&'a T, Output = Option<T>>
+            + Div<&'a T, Output = Option<T>>,
+    {
+        let mean = mean::<T>(data)?;
+        let mut acc = RationalAccumulator::<T>::new();
+
+        for val in data {
+            let dev = (val - &mean)?;
+            acc.add_product(&dev, &dev).ok()?;
+        }
+
+        acc.total().div_int(&T::try_from(data.len()).ok()?)
+    }
+
+    /// Farey sequence of order `n`: every reduced fraction in `[0, 1]` with
+    /// denominator at most `n`, in ascending order. Empty if `n < 1`.
+    /// Stops early if computing the next term overflows
+    pub fn farey<T: CheckGcd + Zero + One + PartialEq + PartialOrd + Clone>(
+        n: T,
+    ) -> impl Iterator<Item = CheckRdc<T>>
+    where
+        for<'a> &'a T: Add<&'a T, Output = Option<T>>
+            + Sub<&'a T, Output = Option<T>>
+            + Mul<&'a T, Output = Option<T>>
+            + Div<&'a T, Output = Option<T>>,
+    {
+        // (a/b, c/d) are two consecutive terms
+        let mut state = (n >= T::ONE).then(|| (T::ZERO, T::ONE, T::ONE, n.clone()));
+
+        core::iter::from_fn(move || {
+            let (a, b, c, d) = state.take()?;
+
+            if !(a.is_one() && b.is_one()) {
+                state = (|| {
+                    let k = (&(&n + &b)? / &d)?;
+                    let e = (&(&k * &c)? - &a)?;
+                    let f = (&(&k * &d)? - &b)?;
+
+                    Some((c, d, e, f))
+                })();
+            }
+
+            Some(CheckRdc { num: a, denom: b })
+        })
+    }
+
+    impl<T: CheckGcd + Zero + One + fmt::Display + PartialEq> fmt::Display for CheckRdc<T> {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            write!(f, "({})/({})", self.num, self.denom)
+        }
+    }
+
+    // Compared through a lossy `num as f64 / denom as f64`, so huge parts only lose
+    // precision. Only a non-finite quotient makes the fractions unequal
+    #[cfg(feature = "approx")]
+    impl<T: CheckGcd + Zero + One + PartialEq + crate::AsF64> CheckRdc<T> {
+        fn approx_f64(&self) -> Option<f64> {
+            let res = self.num.as_f64() / self.denom.as_f64();
+
+            res.is_finite().then_some(res)
+        }
+    }
+
+    #[cfg(feature = "approx")]
+    impl<T: CheckGcd + Zero + One + PartialEq + Clone + crate::AsF64> approx::AbsDiffEq for CheckRdc<T>
+    where
+        for<'a> &'a T: Mul<&'a T, Output = Option<T>> + Div<&'a T, Output = Option<T>>,
+    {
+        type Epsilon = f64;
+
+        fn default_epsilon() -> f64 {
+            <f64 as approx::AbsDiffEq>::default_epsilon()
+        }
+
+        fn abs_diff_eq(&self, other: &Self, epsilon: f64) -> bool {
+            match (self.approx_f64(), other.approx_f64()) {
+                (Some(lhs), Some(rhs)) => approx::AbsDiffEq::abs_diff_eq(&lhs, &rhs, epsilon),
+                _ => false,
+            }
+        }
+    }
+
+    #[cfg(feature = "approx")]
+    impl<T: CheckGcd + Zero + One + PartialEq + Clone + crate::AsF64> approx::RelativeEq for CheckRdc<T>
+    where
+        for<'a> &'a T: Mul<&'a T, Output = Option<T>> + Div<&'a T, Output = Option<T>>,
+    {
+        fn default_max_relative() -> f64 {
+            <f64 as approx::RelativeEq>::default_max_relative()
+        }
+
+        fn relative_eq(&self, other: &Self, epsilon: f64, max_relative: f64) -> bool {
+            match (self.approx_f64(), other.approx_f64()) {
+                (Some(lhs), Some(rhs)) => {
+                    approx::RelativeEq::relative_eq(&lhs, &rhs, epsilon, max_relative)
+                }
+                _ => false,
+            }
+        }
+    }
+
+    #[cfg(feature = "approx")]
+    impl<T: CheckGcd + Zero + One + PartialEq + Clone + crate::AsF64> approx::UlpsEq for CheckRdc<T>
+    where
+        for<'a> &'a T: Mul<&'a T, Output = Option<T>> + Div<&'a T, Output = Option<T>>,
+    {
+        fn default_max_ulps() -> u32 {
+            <f64 as approx::UlpsEq>::default_max_ulps()
+        }
+
+        fn ulps_eq(&self, other: &Self, epsilon: f64, max_ulps: u32) -> bool {
+            match (self.approx_f64(), other.approx_f64()) {
+                (Some(lhs), Some(rhs)) => approx::UlpsEq::ulps_eq(&lhs, &rhs, epsilon, max_ulps),
+                _ => false,
+            }
+        }
+    }
+
+    // Serialized as `{ "num": .., "denom": .. }`. A zero denominator is reported
+    // as a deserialization error instead of panicking like `new` does
+    #[cfg(feature = "serde")]
+    impl<T> serde::Serialize for CheckRdc<T>
+    where
+        T: CheckGcd + Zero + One + PartialEq + serde::Serialize,
+    {
+        fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+            use serde::ser::SerializeStruct;
+
+            let mut state = serializer.serialize_struct("CheckRdc", 2)?;
+            state.serialize_field("num", &self.num)?;
+            state.serialize_field("denom", &self.denom)?;
+            state.end()
+        }
+    }
+
+    #[cfg(feature = "serde")]
+    impl<'de, T> serde::Deserialize<'de> for CheckRdc<T>
+    where
+        T: CheckGcd + Zero + One + PartialEq + serde::Deserialize<'de>,
+        for<'a> &'a T: Div<&'a T, Output = Option<T>>,
+    {
+        fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+            #[derive(serde::Deserialize)]
+            #[serde(rename = "CheckRdc")]
+            struct Parts<T> {
+                num: T,
+                denom: T,
+            }
+
+            let Parts { num, denom } = Parts::<T>::deserialize(deserializer)?;
+
+            Self::try_new(num, denom).map_err(serde::de::Error::custom)
+        }
+    }
+}
+
+pub mod rdc {
+    use crate::{Gcd, One, Zero};
+
+    use core::ops::{Add, Div, Mul, Neg, Sub};
+
+    pub struct Rdc<T: Gcd + Div<T, Output = T> + Zero> {
+        num: T,
+        denom: T,
+    }
+
+    impl<T> Rdc<T>
+    where
+        T: Gcd + Div<T, Output = T> + Clone + Zero,
+    {
+        pub fn num(&self) -> &T {
+            &self.num
+        }
+
+        pub fn denom(&self) -> &T {
+            &self.denom
+        }
+
+        fn simplify(self) -> Self {
+            let gcd = self.num().gcd(self.denom());
+
+            let num = self.num / gcd.clone();
+            let denom = self.denom / gcd;
+
+            Rdc { num, denom }
+        }
+
+        pub fn new(num: T, denom: T) -> Self {
+            let res = Rdc { num, denom };
+
+            res.simplify()
+        }
+    }
+
+    impl<T> Mul for Rdc<T>
+    where
+        T: Gcd + Mul<T, Output = T> + Div<T, Output = T> + Zero + Clone,
+    {
+        type Output = Self;
+
+        fn mul(self, rhs: Self) -> Self::Output {
+            Self::new(self.num * rhs.num, self.denom * rhs.denom)
+        }
+    }
+
+    impl<T> Div for Rdc<T>
+    where
+        T: Gcd + Div<T, Output = T> + Clone + Mul<T, Output = T> + Zero,
+    {
+        type Output = Self;
+
+        fn div(self, rhs: Self) -> Self::Output {
+            Self::new(self.num * rhs.denom, self.denom * rhs.num)
+        }
+    }
+
+    impl<T> Add for Rdc<T>
+    where
+        for<'a> &'a T: Mul<&'a T, Output = T>,
+        T: Gcd + Div<T, Output = T> + Mul<T, Output = T> + Add<T, Output = T> + Clone + Zero,
+    {
+        type Output = Self;
+
+        fn add(self, rhs: Self) -> Self::Output {
+            let denom = self.denom() * rhs.denom();
+
+            let num = self.num * rhs.denom + self.denom * rhs.num;
+
+            Self::new(num, denom)
+        }
+    }
+
+    impl<T> Sub for Rdc<T>
+    where
+        for<'a> &'a T: Mul<&'a T, Output = T>,
+        T: Gcd + Div<T, Output = T> + Mul<T, Output = T> + Sub<T, Output = T> + Clone + Zero,
     {
         type Output = Self;
 
@@ -934,3 +2264,760 @@ pub mod rdc {
 
     // TODO: implement ref and mut ref ops
 }
+
+#[cfg(test)]
+mod tests {
+    use super::checked_reducible::*;
+    use crate::primitives::check_int::CheckI32;
+    use crate::{chrdc, Inv, Pow, Signed};
+
+    fn rdc(num: i32, denom: i32) -> CheckRdc<CheckI32> {
+        CheckRdc::<CheckI32>::new(CheckI32::new(num), CheckI32::new(denom))
+    }
+
+    fn parts(val: &CheckRdc<CheckI32>) -> (CheckI32, CheckI32) {
+        (*val.num(), *val.denom())
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serde_round_trip() {
+        let val = rdc(-3, 4);
+        let json = serde_json::to_string(&val).unwrap();
+
+        assert_eq!(json, r#"{"num":-3,"denom":4}"#);
+        assert_eq!(
+            parts(&serde_json::from_str::<CheckRdc<CheckI32>>(&json).unwrap()),
+            parts(&val)
+        );
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serde_reduces_and_rejects_invalid_parts() {
+        let val: CheckRdc<CheckI32> = serde_json::from_str(r#"{"num":6,"denom":-8}"#).unwrap();
+        assert_eq!(parts(&val), parts(&rdc(-3, 4)));
+
+        assert!(serde_json::from_str::<CheckRdc<CheckI32>>(r#"{"num":1,"denom":0}"#).is_err());
+        assert!(serde_json::from_str::<CheckRdc<CheckI32>>(
+            r#"{"num":-2147483648,"denom":-2147483648}"#
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn pow_squares_and_multiplies() {
+        assert_eq!((&rdc(-2, 3)).pow(3), Some(rdc(-8, 27)));
+        assert_eq!((&rdc(5, 7)).pow(0), Some(rdc(1, 1)));
+        assert_eq!((&rdc(1, 2)).pow(31), None);
+    }
+
+    #[test]
+    fn inv_flips_and_fixes_the_sign() {
+        assert_eq!((&rdc(3, -4)).inv(), Some(rdc(-4, 3)));
+        assert_eq!((&rdc(1, 5)).inv(), Some(rdc(5, 1)));
+        assert_eq!((&rdc(0, 5)).inv(), None);
+    }
+
+    #[test]
+    fn signed_follows_the_normalized_sign() {
+        let half = chrdc!(CheckI32::new(-1), CheckI32::new(2));
+
+        assert!(half.is_negative());
+        assert!(!half.is_positive());
+        assert!(rdc(1, -2).is_negative());
+        assert!(rdc(-1, -2).is_positive());
+        assert!(!rdc(0, -2).is_negative() && !rdc(0, -2).is_positive());
+        assert_eq!(half.abs(), Some(rdc(1, 2)));
+        assert_eq!(half.signum(), rdc(-1, 1));
+    }
+
+    #[test]
+    fn gcd_and_lcm_of_fractions() {
+        assert_eq!(rdc(3, 4).gcd(&rdc(1, 6)), Some(rdc(1, 12)));
+        assert_eq!(rdc(3, 4).lcm(&rdc(1, 6)), Some(rdc(3, 2)));
+        assert_eq!(rdc(6, 8).gcd(&rdc(2, 12)), Some(rdc(1, 12)));
+    }
+
+    #[test]
+    fn try_from_f64_is_exact() {
+        use crate::primitives::check_int::CheckI64;
+
+        let exact = |val: f64| {
+            CheckRdc::<CheckI64>::try_from_f64(val).map(|val| (*val.num(), *val.denom()))
+        };
+        let parts = |num: i64, denom: i64| Ok((CheckI64::new(num), CheckI64::new(denom)));
+
+        assert_eq!(exact(0.5), parts(1, 2));
+        assert_eq!(exact(-0.25), parts(-1, 4));
+        // 0.1 has no finite binary expansion, the nearest f64 is m / 2^55
+        assert_eq!(exact(0.1), parts(3_602_879_701_896_397, 1 << 55));
+        assert_eq!(exact(f64::NAN), Err(FromF64Error::NotFinite));
+        assert_eq!(exact(1e-300), Err(FromF64Error::Overflow));
+        assert_eq!(
+            CheckRdc::<CheckI32>::try_from_f64(0.1).err(),
+            Some(FromF64Error::Overflow)
+        );
+    }
+
+    #[test]
+    fn neg_owned_and_negate() {
+        assert_eq!(-rdc(3, 4), Some(rdc(-3, 4)));
+        assert_eq!(-rdc(0, 4), Some(rdc(0, 1)));
+        assert_eq!(rdc(-3, 4).negate(), Some(rdc(3, 4)));
+        assert_eq!(rdc(0, 4).negate(), Some(rdc(0, 1)));
+
+        // `-MIN` doesn't fit, and moving the sign to the denominator would break its invariant
+        assert_eq!(rdc(i32::MIN, 3).negate(), None);
+    }
+
+    #[test]
+    fn clamp_into_bounds() {
+        assert_eq!(rdc(3, 2).clamp(rdc(0, 1), rdc(1, 1)), rdc(1, 1));
+        assert_eq!(rdc(3, 2).clamp(rdc(2, 1), rdc(3, 1)), rdc(2, 1));
+        assert_eq!(rdc(3, 2).clamp(rdc(1, 1), rdc(2, 1)), rdc(3, 2));
+
+        // cross products overflow, so the comparison takes the fallback path
+        let hi = rdc(i32::MAX - 2, i32::MAX - 1);
+        assert_eq!(rdc(i32::MAX - 1, i32::MAX).clamp(rdc(0, 1), hi.clone()), hi);
+    }
+
+    #[test]
+    #[should_panic(expected = "Invalid clamp bounds")]
+    fn clamp_rejects_inverted_bounds() {
+        rdc(1, 2).clamp(rdc(1, 1), rdc(0, 1));
+    }
+
+    #[test]
+    fn min_and_max() {
+        assert_eq!(rdc(5, 8).min(rdc(1, 2)), rdc(1, 2));
+        assert_eq!(rdc(5, 8).max(rdc(1, 2)), rdc(5, 8));
+        assert_eq!(rdc(1, 2).min(rdc(5, 8)), rdc(1, 2));
+        assert_eq!(rdc(1, 2).max(rdc(5, 8)), rdc(5, 8));
+    }
+
+    #[test]
+    fn midpoint_is_exact() {
+        assert_eq!(rdc(1, 3).midpoint(&rdc(1, 2)), Some(rdc(5, 12)));
+        assert_eq!(rdc(-1, 2).midpoint(&rdc(1, 2)), Some(rdc(0, 1)));
+        assert_eq!(rdc(i32::MAX, 1).midpoint(&rdc(i32::MAX, 1)), None);
+    }
+
+    fn raw(num: i32, denom: i32) -> CheckRdc<CheckI32> {
+        CheckRdc::<CheckI32>::new_raw(CheckI32::new(num), CheckI32::new(denom))
+    }
+
+    #[test]
+    fn new_raw_keeps_parts_until_simplify() {
+        let mut half = raw(2, 4);
+
+        assert_eq!(parts(&half), (CheckI32::new(2), CheckI32::new(4)));
+        assert!(!half.is_reduced());
+        assert_eq!(half, rdc(1, 2));
+
+        half.simplify();
+        assert_eq!(parts(&half), (CheckI32::new(1), CheckI32::new(2)));
+        assert!(half.is_reduced());
+    }
+
+    #[test]
+    fn simplify_normalizes_zero_and_sign() {
+        let mut zero = raw(0, -5);
+        assert_eq!(zero.simplify(), Some(()));
+        assert_eq!(parts(&zero), (CheckI32::new(0), CheckI32::new(1)));
+
+        let mut half = raw(-2, -4);
+        assert_eq!(half.simplify(), Some(()));
+        assert_eq!(parts(&half), (CheckI32::new(1), CheckI32::new(2)));
+
+        let mut neg = raw(3, -6);
+        assert_eq!(neg.simplify(), Some(()));
+        assert_eq!(parts(&neg), (CheckI32::new(-1), CheckI32::new(2)));
+
+        // `|MIN|` doesn't fit, so the sign can't be found. The fraction is left untouched
+        let mut min = raw(1, i32::MIN);
+        assert_eq!(min.checked_simplify(), Err(ArithError::GcdOverflow));
+        assert_eq!(parts(&min), (CheckI32::new(1), CheckI32::new(i32::MIN)));
+    }
+
+    #[test]
+    fn pow_overflow_reaches_the_fraction() {
+        use crate::primitives::check_int::CheckI64;
+
+        // 7^12 is about 1.4e10, past `i32::MAX` but well within `i64`
+        assert_eq!((&rdc(7, 1)).pow(11), Some(rdc(1_977_326_743, 1)));
+        assert_eq!((&rdc(7, 1)).pow(12), None);
+        assert_eq!(
+            (&CheckRdc::<CheckI64>::new(CheckI64::new(7), CheckI64::new(1))).pow(12),
+            Some(CheckRdc::<CheckI64>::new(
+                CheckI64::new(13_841_287_201),
+                CheckI64::new(1)
+            ))
+        );
+    }
+
+    #[test]
+    fn add_near_max_returns_none() {
+        assert_eq!(&rdc(i32::MAX, 1) + &rdc(1, 1), None);
+        assert_eq!(&rdc(i32::MAX - 1, 1) + &rdc(1, 1), Some(rdc(i32::MAX, 1)));
+        assert_eq!(&rdc(i32::MIN, 1) - &rdc(1, 1), None);
+        assert_eq!(&rdc(1, i32::MAX) + &rdc(1, i32::MAX - 1), None);
+    }
+
+    #[test]
+    fn scalar_mul_and_div_by_value() {
+        let half = chrdc!(CheckI32::new(1), CheckI32::new(2));
+
+        assert_eq!(half.clone() * CheckI32::new(3), Some(rdc(3, 2)));
+        assert_eq!(&half * CheckI32::new(-4), Some(rdc(-2, 1)));
+        assert_eq!(half.clone() / CheckI32::new(3), Some(rdc(1, 6)));
+        assert_eq!(half * CheckI32::new(i32::MAX), Some(rdc(i32::MAX, 2)));
+    }
+
+    #[test]
+    fn scalar_add_and_sub() {
+        let one = CheckI32::new(1);
+
+        assert_eq!(&rdc(1, 2) + &one, Some(rdc(3, 2)));
+        assert_eq!(&rdc(1, 2) - &one, Some(rdc(-1, 2)));
+        assert_eq!(&rdc(2, 3) + &CheckI32::new(-2), Some(rdc(-4, 3)));
+        assert_eq!(&rdc(i32::MAX, 1) + &one, None);
+    }
+
+    #[test]
+    fn try_into_f64_rejects_unfaithful_fractions() {
+        use crate::primitives::check_int::CheckI64;
+
+        assert_eq!(f64::try_from(&rdc(-1, 4)), Ok(-0.25));
+        assert_eq!(f64::try_from(&rdc(1, 3)), Ok(1.0 / 3.0));
+
+        // 2^53 + 1 is the first integer without an exact `f64`
+        let past_mantissa =
+            CheckRdc::<CheckI64>::new(CheckI64::new((1 << 53) + 1), CheckI64::new(2));
+        assert_eq!(f64::try_from(&past_mantissa), Err(ToF64Error::Inexact));
+
+        let at_mantissa = CheckRdc::<CheckI64>::new(CheckI64::new(1 << 53), CheckI64::new(3));
+        assert_eq!(f64::try_from(&at_mantissa), Ok((1u64 << 53) as f64 / 3.0));
+    }
+
+    #[test]
+    fn lerp_stays_exact() {
+        assert_eq!(rdc(0, 1).lerp(&rdc(1, 1), &rdc(1, 3)), Some(rdc(1, 3)));
+        assert_eq!(rdc(1, 2).lerp(&rdc(3, 2), &rdc(1, 4)), Some(rdc(3, 4)));
+        assert_eq!(rdc(1, 2).lerp(&rdc(3, 2), &rdc(0, 1)), Some(rdc(1, 2)));
+        assert_eq!(rdc(1, 2).lerp(&rdc(3, 2), &rdc(1, 1)), Some(rdc(3, 2)));
+    }
+
+    #[test]
+    fn checked_simplify_reports_min_overflow() {
+        // the gcd needs `|MIN|`, which doesn't fit
+        let mut min = raw(i32::MIN, -1);
+        assert_eq!(min.checked_simplify(), Err(ArithError::GcdOverflow));
+        assert_eq!(min.simplify(), None);
+        assert_eq!(parts(&min), (CheckI32::new(i32::MIN), CheckI32::new(-1)));
+        assert_eq!(
+            CheckRdc::<CheckI32>::try_new(CheckI32::new(i32::MIN), CheckI32::new(2)).err(),
+            Some(ArithError::GcdOverflow)
+        );
+
+        let mut near_min = raw(i32::MIN + 2, -2);
+        assert_eq!(near_min.checked_simplify(), Ok(()));
+        assert_eq!(
+            parts(&near_min),
+            (CheckI32::new(i32::MAX / 2), CheckI32::new(1))
+        );
+    }
+
+    #[test]
+    fn min_numerators_never_panic() {
+        use crate::primitives::check_int::{CheckI16, CheckI8};
+
+        let r8 = |num, denom| CheckRdc::<CheckI8>::new(CheckI8::new(num), CheckI8::new(denom));
+        let r16 = |num, denom| CheckRdc::<CheckI16>::new(CheckI16::new(num), CheckI16::new(denom));
+
+        let min8 = r8(i8::MIN, 1);
+        assert_eq!(&min8 * &r8(-1, 1), None);
+        assert_eq!(&min8 / &r8(-1, 1), None);
+        assert_eq!(&min8 - &r8(1, 1), None);
+        assert_eq!(&min8 * &r8(1, 2), Some(r8(-64, 1)));
+        assert_eq!(&min8 / &r8(2, 1), Some(r8(-64, 1)));
+        assert_eq!(&min8 + &r8(1, 1), Some(r8(-127, 1)));
+        assert!(min8 < r8(-127, 1));
+
+        let min16 = r16(i16::MIN, 3);
+        assert_eq!(-&min16, None);
+        assert_eq!(&min16 / &r16(1, 2), None);
+        assert_eq!(&min16 + &r16(1, 3), Some(r16(-32767, 3)));
+        // `MIN` itself fits, but reducing `3 * MIN / 3` needs `|MIN|`
+        assert_eq!(&min16 * &r16(3, 1), None);
+    }
+
+    #[test]
+    fn is_zero_checks_the_numerator() {
+        use crate::Zero;
+
+        assert!(chrdc!(CheckI32::new(0), CheckI32::new(7)).is_zero());
+        assert!(raw(0, i32::MIN).is_zero());
+        assert!(!rdc(1, i32::MAX).is_zero());
+    }
+
+    #[test]
+    fn farey_of_order_five() {
+        let expected = [
+            (0, 1),
+            (1, 5),
+            (1, 4),
+            (1, 3),
+            (2, 5),
+            (1, 2),
+            (3, 5),
+            (2, 3),
+            (3, 4),
+            (4, 5),
+            (1, 1),
+        ];
+
+        assert_eq!(farey::<CheckI32>(CheckI32::new(5)).count(), 11);
+        for (term, (num, denom)) in farey::<CheckI32>(CheckI32::new(5)).zip(expected) {
+            assert_eq!(parts(&term), (CheckI32::new(num), CheckI32::new(denom)));
+        }
+
+        assert_eq!(farey::<CheckI32>(CheckI32::new(1)).count(), 2);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn stern_brocot_round_trip() {
+        use Branch::{Left, Right};
+
+        let path = rdc(3, 5).stern_brocot_path().unwrap();
+        assert_eq!(path, [Left, Right, Left]);
+        assert_eq!(
+            CheckRdc::<CheckI32>::from_stern_brocot_path(&path),
+            Some(rdc(3, 5))
+        );
+
+        assert_eq!(rdc(1, 1).stern_brocot_path(), Some(vec![]));
+        assert_eq!(
+            CheckRdc::<CheckI32>::from_stern_brocot_path(&[Right, Right]),
+            Some(rdc(3, 1))
+        );
+    }
+
+    #[test]
+    fn comparison_survives_overflowing_cross_products() {
+        use core::cmp::Ordering;
+
+        // (MAX - 1)^2 and MAX * (MAX - 2) both overflow `i32`
+        let lhs = rdc(i32::MAX - 1, i32::MAX);
+        let rhs = rdc(i32::MAX - 2, i32::MAX - 1);
+
+        assert_eq!(lhs.partial_cmp(&rhs), Some(Ordering::Greater));
+        assert_eq!(rhs.partial_cmp(&lhs), Some(Ordering::Less));
+        assert!(lhs != rhs);
+        assert!(lhs == lhs.clone());
+        assert!(rdc(-(i32::MAX - 1), i32::MAX) < rdc(-(i32::MAX - 2), i32::MAX - 1));
+    }
+
+    #[test]
+    fn comparison_with_raw_negative_denominators() {
+        use core::cmp::Ordering;
+
+        let raw =
+            |num, denom| CheckRdc::<CheckI32>::new_raw(CheckI32::new(num), CheckI32::new(denom));
+
+        // 1/-2 is -1/2, below 1/3 even though 1 * 3 > 1 * -2
+        assert_eq!(raw(1, -2).partial_cmp(&rdc(1, 3)), Some(Ordering::Less));
+        assert_eq!(rdc(1, 3).partial_cmp(&raw(1, -2)), Some(Ordering::Greater));
+        assert_eq!(
+            raw(-1, -2).partial_cmp(&raw(1, -3)),
+            Some(Ordering::Greater)
+        );
+        assert_eq!(raw(2, -4).partial_cmp(&rdc(-1, 2)), Some(Ordering::Equal));
+        assert!(raw(3, -1) < CheckI32::new(0));
+
+        // the continued fraction path when the cross products overflow
+        let lhs = raw(i32::MAX - 1, -i32::MAX);
+        let rhs = rdc(-(i32::MAX - 2), i32::MAX - 1);
+        assert_eq!(lhs.partial_cmp(&rhs), Some(Ordering::Less));
+        assert_eq!(rhs.partial_cmp(&lhs), Some(Ordering::Greater));
+    }
+
+    #[test]
+    fn parts_of_a_reduced_fraction() {
+        let val = chrdc!(CheckI32::new(6), CheckI32::new(8));
+
+        assert_eq!(val.as_parts(), (&CheckI32::new(3), &CheckI32::new(4)));
+        assert_eq!(val.into_parts(), (CheckI32::new(3), CheckI32::new(4)));
+    }
+
+    #[test]
+    fn unsigned_fractions_are_nonnegative() {
+        use crate::primitives::check_int::CheckU64;
+
+        let ru = |num, denom| CheckRdc::<CheckU64>::new(CheckU64::new(num), CheckU64::new(denom));
+
+        assert_eq!(&ru(1, 2) - &ru(1, 4), Some(ru(1, 4)));
+        assert_eq!(&ru(1, 2) - &ru(1, 2), Some(ru(0, 1)));
+        // `None` means "would be negative" here, not only overflow
+        assert_eq!(&ru(1, 4) - &ru(1, 2), None);
+        assert_eq!(&ru(u64::MAX, 1) + &ru(1, 1), None);
+    }
+
+    #[test]
+    fn solve2x2_is_exact() {
+        // x + y = 1, 2x - y = 0
+        let a = [[rdc(1, 1), rdc(1, 1)], [rdc(2, 1), rdc(-1, 1)]];
+        let [x, y] = solve2x2::<CheckI32>(a, [rdc(1, 1), rdc(0, 1)]).unwrap();
+
+        assert_eq!(parts(&x), (CheckI32::new(1), CheckI32::new(3)));
+        assert_eq!(parts(&y), (CheckI32::new(2), CheckI32::new(3)));
+
+        let singular = [[rdc(1, 2), rdc(1, 1)], [rdc(1, 1), rdc(2, 1)]];
+        assert!(solve2x2::<CheckI32>(singular, [rdc(1, 1), rdc(1, 1)]).is_none());
+    }
+
+    #[test]
+    fn fused_mul_add_avoids_overflow() {
+        let (a, b, c) = (rdc(1 << 16, 3), rdc(1 << 15, 1), rdc(-2, 3));
+
+        // the product 2^31/3 is irreducible and overflows, while the sum
+        // (2^31 - 2)/3 fits once `c` is folded into `a` first
+        let naive = (&a * &b).and_then(|prod| &prod + &c);
+        assert_eq!(naive, None);
+        assert_eq!(a.mul_add(&b, &c), Some(rdc(715_827_882, 1)));
+
+        let small = rdc(1, 2).mul_add(&rdc(2, 3), &rdc(1, 6));
+        assert_eq!(small, Some(rdc(1, 2)));
+    }
+
+    #[test]
+    fn default_is_zero() {
+        use crate::Zero;
+
+        let val = CheckRdc::<CheckI32>::default();
+
+        assert!(val.is_zero());
+        assert_eq!(parts(&val), (CheckI32::new(0), CheckI32::new(1)));
+    }
+
+    #[test]
+    fn chrdc_forms() {
+        let two = CheckI32::new(2);
+        let four = CheckI32::new(4);
+
+        assert_eq!(parts(&chrdc!(two, four)), (CheckI32::new(1), two));
+        assert_eq!(parts(&chrdc!((two, four))), (CheckI32::new(1), two));
+        assert_eq!(
+            parts(&chrdc!(ty: CheckI32)),
+            (CheckI32::new(1), CheckI32::new(1))
+        );
+
+        // a tuple-valued expression goes through the single-argument form
+        let pair = (four, CheckI32::new(-6));
+        assert_eq!(parts(&chrdc!(pair)), (CheckI32::new(-2), CheckI32::new(3)));
+    }
+
+    #[test]
+    fn reduce_leaves_the_original() {
+        let val = raw(6, 8);
+
+        assert_eq!(
+            val.reduce().map(|val| parts(&val)),
+            Some((CheckI32::new(3), CheckI32::new(4)))
+        );
+        assert_eq!(parts(&val), (CheckI32::new(6), CheckI32::new(8)));
+        assert_eq!(raw(1, i32::MIN).reduce(), None);
+    }
+
+    #[test]
+    fn compare_with_a_scalar() {
+        let one = CheckI32::new(1);
+
+        assert!(chrdc!(CheckI32::new(3), CheckI32::new(2)) > one);
+        assert!(chrdc!(CheckI32::new(1), CheckI32::new(2)) < one);
+        assert!(rdc(4, 4) == one);
+        assert!(rdc(-1, 2) < CheckI32::new(0));
+        // `MAX * 2` overflows, the comparison still holds
+        assert!(rdc(i32::MAX, 2) < CheckI32::new(i32::MAX));
+    }
+
+    #[test]
+    fn horner_evaluates_exactly() {
+        // x^2 + 1/2
+        let coeffs = [rdc(1, 1), rdc(0, 1), rdc(1, 2)];
+
+        assert_eq!(
+            parts(&horner::<CheckI32>(&coeffs, &rdc(1, 3)).unwrap()),
+            parts(&rdc(11, 18))
+        );
+        assert_eq!(horner::<CheckI32>(&[], &rdc(1, 3)), Some(rdc(0, 1)));
+    }
+
+    #[test]
+    fn clamp_denominator_finds_best_approximation() {
+        let pi = rdc(355, 113);
+
+        assert_eq!(
+            parts(&pi.clamp_denominator(CheckI32::new(10)).unwrap()),
+            parts(&rdc(22, 7))
+        );
+        assert_eq!(
+            parts(&pi.clamp_denominator(CheckI32::new(1)).unwrap()),
+            parts(&rdc(3, 1))
+        );
+        // already fits, only reduced
+        assert_eq!(
+            parts(&raw(6, 8).clamp_denominator(CheckI32::new(10)).unwrap()),
+            parts(&rdc(3, 4))
+        );
+        assert_eq!(
+            parts(&rdc(-355, 113).clamp_denominator(CheckI32::new(10)).unwrap()),
+            parts(&rdc(-22, 7))
+        );
+    }
+
+    #[test]
+    fn lcm_addition_uses_the_smaller_denominator() {
+        let (a, b) = (rdc(1, 6), rdc(1, 8));
+
+        assert_eq!(
+            parts(&(&a + &b).unwrap()),
+            (CheckI32::new(14), CheckI32::new(48))
+        );
+        assert_eq!(
+            parts(&a.add_lcm(&b).unwrap()),
+            (CheckI32::new(7), CheckI32::new(24))
+        );
+        assert_eq!(
+            parts(&a.sub_lcm(&b).unwrap()),
+            (CheckI32::new(1), CheckI32::new(24))
+        );
+    }
+
+    #[test]
+    fn from_tuple_reduces() {
+        let val = CheckRdc::<CheckI32>::from((CheckI32::new(6), CheckI32::new(-8)));
+
+        assert_eq!(parts(&val), (CheckI32::new(-3), CheckI32::new(4)));
+        assert_eq!(
+            CheckRdc::<CheckI32>::try_new(CheckI32::new(6), CheckI32::new(-8)).ok(),
+            Some(val)
+        );
+        assert_eq!(
+            CheckRdc::<CheckI32>::try_new(CheckI32::new(1), CheckI32::new(0)).err(),
+            Some(ArithError::ZeroDenominator)
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "Zero denominator")]
+    fn from_tuple_panics_on_zero_denominator() {
+        let _ = CheckRdc::<CheckI32>::from((CheckI32::new(1), CheckI32::new(0)));
+    }
+
+    #[test]
+    fn scale_cancels_before_multiplying() {
+        use core::ops::Mul;
+
+        let quarter = rdc(1, 4);
+        let two = CheckI32::new(2);
+
+        assert_eq!(
+            parts(&Mul::mul(&quarter, &two).unwrap()),
+            (CheckI32::new(2), CheckI32::new(4))
+        );
+        assert_eq!(
+            parts(&quarter.scale(&two).unwrap()),
+            (CheckI32::new(1), CheckI32::new(2))
+        );
+
+        // the plain product fits, so `*` keeps the whole common factor
+        let k = CheckI32::new(1 << 20);
+        let small = rdc(3, 1 << 20);
+        assert_eq!(
+            parts(&Mul::mul(&small, &k).unwrap()),
+            (CheckI32::new(3 << 20), k)
+        );
+        assert_eq!(
+            parts(&small.scale(&k).unwrap()),
+            (CheckI32::new(3), CheckI32::new(1))
+        );
+
+        assert_eq!(
+            parts(&rdc(6, 7).div_int(&CheckI32::new(-4)).unwrap()),
+            (CheckI32::new(-3), CheckI32::new(14))
+        );
+        assert_eq!(rdc(i32::MAX, 1).scale(&CheckI32::new(2)), None);
+    }
+
+    #[test]
+    fn harmonic_sum_stays_reduced() {
+        let harmonic = |n: i32| (1..=n).map(|k| rdc(1, k));
+
+        assert_eq!(
+            parts(&sum_reduced::<CheckI32>(harmonic(4)).unwrap()),
+            (CheckI32::new(25), CheckI32::new(12))
+        );
+
+        // `+` only reduces once a product overflows, so its partial sums carry n!
+        let naive = harmonic(12).try_fold(rdc(0, 1), |acc, val| &acc + &val);
+        assert_eq!(
+            parts(&naive.unwrap()),
+            (CheckI32::new(1_486_442_880), CheckI32::new(479_001_600))
+        );
+        assert_eq!(
+            parts(&sum_reduced::<CheckI32>(harmonic(12)).unwrap()),
+            (CheckI32::new(86_021), CheckI32::new(27_720))
+        );
+
+        // the reduced denominator of H(25) is past `i32::MAX`
+        assert!(sum_reduced::<CheckI32>(harmonic(24)).is_some());
+        assert_eq!(sum_reduced::<CheckI32>(harmonic(25)), None);
+    }
+
+    #[cfg(feature = "approx")]
+    #[test]
+    fn approx_macros_compare_values() {
+        use crate::primitives::check_int::CheckI64;
+
+        approx::assert_relative_eq!(rdc(1, 3), rdc(333_333, 1_000_000), epsilon = 1e-6);
+        approx::assert_abs_diff_eq!(rdc(2, 4), rdc(1, 2));
+        approx::assert_ulps_ne!(rdc(1, 3), rdc(1, 4));
+
+        // parts past 2^53 are rounded instead of refused
+        let near_one =
+            CheckRdc::<CheckI64>::new_raw(CheckI64::new((1 << 60) + 1), CheckI64::new(1 << 60));
+        approx::assert_relative_eq!(
+            near_one,
+            CheckRdc::<CheckI64>::new(CheckI64::new(1), CheckI64::new(1))
+        );
+    }
+
+    #[test]
+    fn denominator_stays_positive() {
+        let zero = CheckI32::new(0);
+        let neg = (&rdc(1, 3) - &rdc(1, 2)).unwrap();
+
+        let results = [
+            neg.clone(),
+            (&neg * &rdc(3, -5)).unwrap(),
+            (&neg / &rdc(-3, 5)).unwrap(),
+            (&rdc(1, 2) / &rdc(-3, 5)).unwrap(),
+            core::ops::Div::div(&rdc(1, 2), &CheckI32::new(-7)).unwrap(),
+            (&neg).inv().unwrap(),
+            (-neg.clone()).unwrap(),
+            (&rdc(-1, 4) + &rdc(-1, 4)).unwrap(),
+            rdc(3, -4),
+        ];
+
+        for val in &results {
+            assert!(*val.denom() > zero);
+            assert_eq!(val.num_is_negative(), Signed::is_negative(val));
+        }
+
+        assert!(neg.num_is_negative());
+        assert_eq!(parts(&neg), (CheckI32::new(-1), CheckI32::new(6)));
+        assert!(!results[1].num_is_negative());
+    }
+
+    #[test]
+    fn exact_square_roots() {
+        assert_eq!(parts(&rdc(4, 9).checked_sqrt().unwrap()), parts(&rdc(2, 3)));
+        assert_eq!(rdc(2, 1).checked_sqrt(), None);
+        // reduced first, 8/18 is 4/9
+        assert_eq!(
+            parts(&raw(8, 18).checked_sqrt().unwrap()),
+            parts(&rdc(2, 3))
+        );
+        assert_eq!(rdc(1, 2).checked_sqrt(), None);
+        assert_eq!(rdc(-4, 9).checked_sqrt(), None);
+        assert_eq!(rdc(0, 1).checked_sqrt(), Some(rdc(0, 1)));
+    }
+
+    #[test]
+    fn round_to_a_rational_grid() {
+        let eighth = rdc(1, 8);
+
+        assert_eq!(
+            parts(&rdc(7, 16).round_to_multiple(&eighth).unwrap()),
+            parts(&rdc(1, 2))
+        );
+        assert_eq!(
+            parts(&rdc(5, 16).round_to_multiple(&eighth).unwrap()),
+            parts(&rdc(3, 8))
+        );
+        assert_eq!(
+            parts(&rdc(-7, 16).round_to_multiple(&eighth).unwrap()),
+            parts(&rdc(-1, 2))
+        );
+        assert_eq!(
+            parts(&rdc(1, 3).round_to_multiple(&eighth).unwrap()),
+            parts(&rdc(3, 8))
+        );
+        assert_eq!(rdc(1, 20).round_to_multiple(&eighth), Some(rdc(0, 1)));
+    }
+
+    #[test]
+    #[should_panic(expected = "Rounding to a multiple of zero")]
+    fn round_to_a_zero_step_panics() {
+        let _ = rdc(1, 2).round_to_multiple(&rdc(0, 1));
+    }
+
+    #[test]
+    fn accumulate_a_rational_dot_product() {
+        let a = [rdc(1, 2), rdc(1, 3), rdc(1, 4), rdc(1, 5)];
+        let b = [rdc(2, 3), rdc(3, 4), rdc(4, 5), rdc(5, 6)];
+
+        let mut acc = RationalAccumulator::<CheckI32>::new();
+        for (a, b) in a.iter().zip(&b) {
+            acc.add_product(a, b).unwrap();
+        }
+
+        // 1/3 + 1/4 + 1/5 + 1/6
+        assert_eq!(parts(acc.total()), parts(&rdc(19, 20)));
+
+        let before = acc.total().clone();
+        assert_eq!(
+            acc.add_product(&rdc(i32::MAX, 1), &rdc(2, 1)),
+            Err(ArithError::MulOverflow)
+        );
+        assert_eq!(parts(&acc.into_total()), parts(&before));
+    }
+
+    #[test]
+    fn exact_mean_and_variance() {
+        let data = [rdc(1, 2), rdc(1, 3), rdc(1, 6)];
+
+        assert_eq!(parts(&mean::<CheckI32>(&data).unwrap()), parts(&rdc(1, 3)));
+        assert_eq!(
+            parts(&variance::<CheckI32>(&data).unwrap()),
+            parts(&rdc(1, 54))
+        );
+        assert_eq!(mean::<CheckI32>(&[]), None);
+        assert_eq!(variance::<CheckI32>(&[]), None);
+        assert_eq!(variance::<CheckI32>(&[rdc(2, 3)]), Some(rdc(0, 1)));
+    }
+
+    #[test]
+    fn widening_add_keeps_the_result() {
+        use crate::primitives::check_int::CheckI64;
+
+        let big = rdc(i32::MAX, 1);
+        let one = rdc(1, 1);
+
+        assert_eq!(&big + &one, None);
+
+        let wide = big.add_widening(&one).unwrap();
+        assert_eq!(
+            wide.into_parts(),
+            (CheckI64::new(i32::MAX as i64 + 1), CheckI64::new(1))
+        );
+
+        // fits in `i32`, only widened afterwards
+        assert_eq!(
+            rdc(1, 2).add_widening(&rdc(1, 3)).unwrap().into_parts(),
+            (CheckI64::new(5), CheckI64::new(6))
+        );
+    }
+}
@@ -0,0 +1,2066 @@
+use crate::{Inv, One, Pow, Signed, Zero};
+use core::ops::{
+    Add, AddAssign, BitAnd, BitAndAssign, BitOr, BitOrAssign, BitXor, BitXorAssign, Div, DivAssign,
+    Index, IndexMut, Mul, MulAssign, Neg, Rem, RemAssign, Sub, SubAssign,
+};
+use paste::paste;
+
+/// Vector of `N` `f32` components. All operators work component-wise.
+/// `%` is the `f32` remainder, which keeps the sign of the left operand,
+/// see `rem_euclid` for wrapping into `[0, rhs)`
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[repr(transparent)]
+pub struct Vector<const N: usize>([f32; N]);
+
+pub type Vec2 = Vector<2>;
+pub type Vec3 = Vector<3>;
+pub type Vec4 = Vector<4>;
+
+/// Same as `Vector`, but with `f64` components. Mixing the two in arithmetic
+/// doesn't compile, so precision is never lost silently:
+///
+/// ```compile_fail
+/// use malgebra::vector::{DVec2, Vec2};
+///
+/// let sum = Vec2::ONE + DVec2::ONE;
+/// ```
+///
+/// Convert one side explicitly instead:
+///
+/// ```
+/// use malgebra::vector::{DVec2, Vec2};
+///
+/// assert_eq!(Vec2::ONE.as_dvec2() + DVec2::ONE, DVec2::splat(2.0));
+/// assert_eq!(Vec2::ONE + DVec2::ONE.as_vec2(), Vec2::splat(2.0));
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[repr(transparent)]
+pub struct DVector<const N: usize>([f64; N]);
+
+pub type DVec2 = DVector<2>;
+
+/// Vector of `N` `i32` components for grid and pixel coordinates.
+/// Operators follow `i32`: `/` truncates towards zero, while overflow and
+/// division by zero panic in debug builds
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[repr(transparent)]
+pub struct IVector<const N: usize>([i32; N]);
+
+pub type IVec2 = IVector<2>;
+
+/// Dot product shared by the vector types, for code generic over them.
+/// The inherent `dot` methods do the same
+pub trait Dot {
+    type Output;
+
+    fn dot(&self, rhs: &Self) -> Self::Output;
+}
+
+// Each operator is given as `(Trait, method)`. The method name is spelled out
+// rather than derived from the trait with `[<$op:snake>]`, since that gives
+// `bit_xor` for `BitXor` while the method is `bitxor`. The assign variants are
+// then always `[<$op Assign>]` and `[<$fn_name _assign>]`
+macro_rules! impl_ops {
+    ($vec:ident, $scalar:ty: $(($op:ident, $fn_name:ident));*) => {
+        $(
+            impl<const N: usize> $op for $vec<N> {
+                type Output = Self;
+
+                fn $fn_name(self, rhs: Self) -> Self::Output {
+                    Self(core::array::from_fn(|i| self.0[i].$fn_name(rhs.0[i])))
+                }
+            }
+
+            impl<const N: usize> $op<$scalar> for $vec<N> {
+                type Output = Self;
+
+                fn $fn_name(self, rhs: $scalar) -> Self::Output {
+                    Self(core::array::from_fn(|i| self.0[i].$fn_name(rhs)))
+                }
+            }
+
+            impl<const N: usize> $op<$vec<N>> for $scalar {
+                type Output = $vec<N>;
+
+                fn $fn_name(self, rhs: $vec<N>) -> Self::Output {
+                    $vec(core::array::from_fn(|i| self.$fn_name(rhs.0[i])))
+                }
+            }
+
+            paste! {
+                impl<const N: usize> [<$op Assign>] for $vec<N> {
+                    fn [<$fn_name _assign>](&mut self, rhs: Self) {
+                        *self = (*self).$fn_name(rhs);
+                    }
+                }
+
+                impl<const N: usize> [<$op Assign>]<$scalar> for $vec<N> {
+                    fn [<$fn_name _assign>](&mut self, rhs: $scalar) {
+                        *self = (*self).$fn_name(rhs);
+                    }
+                }
+            }
+        )*
+    };
+}
+
+impl_ops!(Vector, f32: (Add, add); (Sub, sub); (Mul, mul); (Div, div); (Rem, rem));
+impl_ops!(DVector, f64: (Add, add); (Sub, sub); (Mul, mul); (Div, div); (Rem, rem));
+impl_ops!(IVector, i32: (Add, add); (Sub, sub); (Mul, mul); (Div, div); (Rem, rem));
+impl_ops!(IVector, i32: (BitAnd, bitand); (BitOr, bitor); (BitXor, bitxor));
+
+/// Constructors, conversions, indexing and `Zero`/`One` shared by the vector types
+macro_rules! impl_basics {
+    ($vec:ident, $scalar:ty: $zero:expr, $one:expr) => {
+        impl<const N: usize> $vec<N> {
+            pub const ZERO: Self = Self([$zero; N]);
+            pub const ONE: Self = Self([$one; N]);
+
+            pub const fn from_array(arr: [$scalar; N]) -> Self {
+                Self(arr)
+            }
+
+            pub const fn splat(val: $scalar) -> Self {
+                Self([val; N])
+            }
+
+            /// Returns `None` unless `slice` has exactly `N` elements.
+            /// Take a subslice first to read from a longer buffer
+            pub fn try_from_slice(slice: &[$scalar]) -> Option<Self> {
+                Self::try_from(slice).ok()
+            }
+
+            pub const fn as_array(&self) -> &[$scalar; N] {
+                &self.0
+            }
+
+            pub const fn to_array(self) -> [$scalar; N] {
+                self.0
+            }
+        }
+
+        impl<const N: usize> Neg for $vec<N> {
+            type Output = Self;
+
+            fn neg(self) -> Self::Output {
+                Self(self.0.map(|val| -val))
+            }
+        }
+
+        impl<const N: usize> From<[$scalar; N]> for $vec<N> {
+            fn from(arr: [$scalar; N]) -> Self {
+                Self(arr)
+            }
+        }
+
+        impl<const N: usize> From<$vec<N>> for [$scalar; N] {
+            fn from(vec: $vec<N>) -> Self {
+                vec.0
+            }
+        }
+
+        /// Fails unless the slice has exactly `N` elements
+        impl<const N: usize> TryFrom<&[$scalar]> for $vec<N> {
+            type Error = core::array::TryFromSliceError;
+
+            fn try_from(slice: &[$scalar]) -> Result<Self, Self::Error> {
+                <[$scalar; N]>::try_from(slice).map(Self)
+            }
+        }
+
+        impl<const N: usize> Index<usize> for $vec<N> {
+            type Output = $scalar;
+
+            fn index(&self, index: usize) -> &Self::Output {
+                &self.0[index]
+            }
+        }
+
+        impl<const N: usize> IndexMut<usize> for $vec<N> {
+            fn index_mut(&mut self, index: usize) -> &mut Self::Output {
+                &mut self.0[index]
+            }
+        }
+
+        impl<const N: usize> Zero for $vec<N> {
+            const ZERO: Self = $vec::ZERO;
+        }
+
+        impl<const N: usize> One for $vec<N> {
+            const ONE: Self = $vec::ONE;
+        }
+
+        impl<const N: usize> Default for $vec<N> {
+            fn default() -> Self {
+                Self::ZERO
+            }
+        }
+
+        impl<const N: usize> core::iter::Sum for $vec<N> {
+            fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+                iter.fold(Self::ZERO, |acc, val| acc + val)
+            }
+        }
+
+        impl<'a, const N: usize> core::iter::Sum<&'a $vec<N>> for $vec<N> {
+            fn sum<I: Iterator<Item = &'a Self>>(iter: I) -> Self {
+                iter.copied().sum()
+            }
+        }
+
+        /// Component-wise product, starting from `ONE`
+        impl<const N: usize> core::iter::Product for $vec<N> {
+            fn product<I: Iterator<Item = Self>>(iter: I) -> Self {
+                iter.fold(Self::ONE, |acc, val| acc * val)
+            }
+        }
+
+        impl<'a, const N: usize> core::iter::Product<&'a $vec<N>> for $vec<N> {
+            fn product<I: Iterator<Item = &'a Self>>(iter: I) -> Self {
+                iter.copied().product()
+            }
+        }
+    };
+}
+
+impl_basics!(Vector, f32: 0.0, 1.0);
+impl_basics!(DVector, f64: 0.0, 1.0);
+impl_basics!(IVector, i32: 0, 1);
+
+impl<const N: usize> Vector<N> {
+    /// Applies `f` to every component
+    pub fn map(&self, f: impl Fn(f32) -> f32) -> Self {
+        Self(self.0.map(f))
+    }
+
+    /// Applies `f` to every pair of components of `self` and `other`
+    pub fn zip_map(&self, other: &Self, f: impl Fn(f32, f32) -> f32) -> Self {
+        Self(core::array::from_fn(|i| f(self.0[i], other.0[i])))
+    }
+
+    /// Raw bit patterns of the components, see `f32::to_bits`. Unlike the vector
+    /// itself they are `Eq + Hash`, so they can key a map. Keep in mind that
+    /// `0.0` and `-0.0` get different bits while equal NaNs may not
+    pub fn to_bits(&self) -> [u32; N] {
+        self.0.map(f32::to_bits)
+    }
+
+    /// Inverse of `to_bits`, restores every component bit for bit
+    pub fn from_bits(bits: [u32; N]) -> Self {
+        Self(bits.map(f32::from_bits))
+    }
+
+    /// `self * mul + add` per component with a single rounding, see `f32::mul_add`.
+    /// Falls back to a slower software routine on targets without FMA
+    pub fn mul_add(&self, mul: &Self, add: &Self) -> Self {
+        Self(core::array::from_fn(|i| {
+            self.0[i].mul_add(mul.0[i], add.0[i])
+        }))
+    }
+
+    pub fn dot(&self, rhs: &Self) -> f32 {
+        (*self * *rhs).element_sum()
+    }
+
+    /// Same as `dot`, but usable in const contexts
+    pub const fn dot_const(&self, rhs: &Self) -> f32 {
+        let mut res = 0.0;
+        let mut i = 0;
+
+        while i < N {
+            res += self.0[i] * rhs.0[i];
+            i += 1;
+        }
+
+        res
+    }
+
+    /// Cosine of the angle between the vectors.
+    /// Returns `None` if either vector has zero length
+    pub fn cos_angle(&self, rhs: &Self) -> Option<f32> {
+        let lengths = self.length() * rhs.length();
+
+        if lengths == 0.0 {
+            return None;
+        }
+
+        Some((self.dot(rhs) / lengths).clamp(-1.0, 1.0))
+    }
+
+    /// `|dot| <= eps`. The zero vector is perpendicular to everything
+    pub fn is_perpendicular(&self, rhs: &Self, eps: f32) -> bool {
+        self.dot(rhs).abs() <= eps
+    }
+
+    pub fn length_squared(&self) -> f32 {
+        self.dot(self)
+    }
+
+    pub fn length(&self) -> f32 {
+        self.length_squared().sqrt()
+    }
+
+    /// The zero vector has no direction, so normalizing it gives NaN components
+    pub fn normalize(&self) -> Self {
+        *self / self.length()
+    }
+
+    pub fn midpoint(&self, other: &Self) -> Self {
+        (*self + *other) * 0.5
+    }
+
+    /// Steps from `self` towards `target` by at most `max_delta`. Returns `target`
+    /// exactly once it is within reach, so repeated calls never overshoot
+    pub fn move_towards(&self, target: &Self, max_delta: f32) -> Self {
+        let delta = *target - *self;
+        let len = delta.length();
+
+        if len <= max_delta || len == 0.0 {
+            return *target;
+        }
+
+        *self + delta * (max_delta / len)
+    }
+
+    /// `true` if no component is NaN or infinite
+    pub fn is_finite(&self) -> bool {
+        self.0.iter().all(|val| val.is_finite())
+    }
+
+    /// `true` if any component is NaN
+    pub fn is_nan(&self) -> bool {
+        self.0.iter().any(|val| val.is_nan())
+    }
+
+    /// `true` if the length is within about `1e-4` of `1.0`, which is looser than
+    /// the rounding error of `normalize`. Checks `length_squared` to skip the root
+    pub fn is_normalized(&self) -> bool {
+        (self.length_squared() - 1.0).abs() <= 2e-4
+    }
+
+    /// Rescales `self` so its length is within `[min, max]`, keeping the direction.
+    /// The zero vector has no direction and stays zero.
+    /// Panics if `min > max` or either is NaN, like `f32::clamp`
+    pub fn clamp_length(&self, min: f32, max: f32) -> Self {
+        let len = self.length();
+
+        if len == 0.0 {
+            return *self;
+        }
+
+        *self * (len.clamp(min, max) / len)
+    }
+
+    /// Shortens `self` to length `max` if it is longer
+    pub fn clamp_length_max(&self, max: f32) -> Self {
+        let len = self.length();
+
+        if len > max {
+            *self * (max / len)
+        } else {
+            *self
+        }
+    }
+
+    /// Extends `self` to length `min` if it is shorter.
+    /// The zero vector has no direction and stays zero
+    pub fn clamp_length_min(&self, min: f32) -> Self {
+        let len = self.length();
+
+        if len < min && len != 0.0 {
+            *self * (min / len)
+        } else {
+            *self
+        }
+    }
+
+    pub fn element_sum(&self) -> f32 {
+        self.0.iter().sum()
+    }
+
+    pub fn element_product(&self) -> f32 {
+        self.0.iter().product()
+    }
+
+    pub fn min_element(&self) -> f32 {
+        self.0.iter().copied().fold(f32::INFINITY, f32::min)
+    }
+
+    pub fn max_element(&self) -> f32 {
+        self.0.iter().copied().fold(f32::NEG_INFINITY, f32::max)
+    }
+
+    /// `|self[i] - other[i]| <= epsilon` for every component
+    pub fn abs_diff_eq(&self, other: &Self, epsilon: f32) -> bool {
+        (0..N).all(|i| (self.0[i] - other.0[i]).abs() <= epsilon)
+    }
+
+    /// Like `abs_diff_eq`, but components may also differ by `max_relative` times
+    /// the larger of their magnitudes. Infinite components only match themselves
+    pub fn relative_eq(&self, other: &Self, epsilon: f32, max_relative: f32) -> bool {
+        (0..N).all(|i| {
+            let (lhs, rhs) = (self.0[i], other.0[i]);
+
+            if lhs == rhs {
+                return true;
+            }
+
+            if lhs.is_infinite() || rhs.is_infinite() {
+                return false;
+            }
+
+            let diff = (lhs - rhs).abs();
+
+            diff <= epsilon || diff <= lhs.abs().max(rhs.abs()) * max_relative
+        })
+    }
+
+    /// Raises every component to `n`, see `f32::powf`
+    pub fn powf(&self, n: f32) -> Self {
+        self.map(|val| val.powf(n))
+    }
+
+    /// Square root per component. Negative components give NaN
+    pub fn sqrt(&self) -> Self {
+        self.map(f32::sqrt)
+    }
+
+    /// `1.0 / x` per component. Zero components become infinite
+    pub fn recip(&self) -> Self {
+        self.map(f32::recip)
+    }
+
+    /// Sign per component, see `f32::signum`. Zero gives `1.0`, not `0.0`
+    pub fn signum(&self) -> Self {
+        self.map(f32::signum)
+    }
+
+    /// Euclidean remainder per component, see `f32::rem_euclid`. Unlike `%` the
+    /// result is never negative, so it wraps coordinates into a tile
+    pub fn rem_euclid(&self, rhs: &Self) -> Self {
+        self.zip_map(rhs, f32::rem_euclid)
+    }
+
+    /// Lexicographic order using `f32::total_cmp` on each component, so it can sort
+    /// vectors deterministically, NaNs included. It has no geometric meaning
+    pub fn total_cmp(&self, rhs: &Self) -> core::cmp::Ordering {
+        self.0
+            .iter()
+            .zip(&rhs.0)
+            .map(|(lhs, rhs)| lhs.total_cmp(rhs))
+            .find(|ord| ord.is_ne())
+            .unwrap_or(core::cmp::Ordering::Equal)
+    }
+
+    /// Reflects off a surface with the unit normal `normal`.
+    /// A non-normalized `normal` gives a wrong result, see `reflect_unnormalized`
+    pub fn reflect(&self, normal: &Self) -> Self {
+        *self - *normal * (2.0 * self.dot(normal))
+    }
+
+    /// Same as `reflect`, but `normal` can have any nonzero length.
+    /// A zero `normal` gives NaN components
+    pub fn reflect_unnormalized(&self, normal: &Self) -> Self {
+        *self - *normal * (2.0 * self.dot(normal) / normal.length_squared())
+    }
+
+    /// Magnitudes of `self` with the signs of `sign` per component, see `f32::copysign`
+    pub fn copysign(&self, sign: &Self) -> Self {
+        Self(core::array::from_fn(|i| self.0[i].copysign(sign.0[i])))
+    }
+
+    /// Takes the component of `a` where `mask` is `true` and of `b` otherwise
+    pub fn select(mask: [bool; N], a: Self, b: Self) -> Self {
+        Self(core::array::from_fn(
+            |i| if mask[i] { a.0[i] } else { b.0[i] },
+        ))
+    }
+
+    /// Rounds each component half away from zero, like `f32::round`
+    pub fn round(&self) -> Self {
+        Self(self.0.map(f32::round))
+    }
+
+    /// Rounds each component half to even, like `f32::round_ties_even`.
+    /// Unlike `round`, `0.5` and `1.5` round to `0.0` and `2.0`, so ties carry no bias
+    pub fn round_ties_even(&self) -> Self {
+        Self(self.0.map(f32::round_ties_even))
+    }
+}
+
+impl Vector<2> {
+    pub const X: Self = Self([1.0, 0.0]);
+    pub const Y: Self = Self([0.0, 1.0]);
+    pub const NEG_X: Self = Self([-1.0, 0.0]);
+    pub const NEG_Y: Self = Self([0.0, -1.0]);
+
+    pub const fn new(x: f32, y: f32) -> Self {
+        Self([x, y])
+    }
+
+    pub const fn x(&self) -> f32 {
+        self.0[0]
+    }
+
+    pub const fn y(&self) -> f32 {
+        self.0[1]
+    }
+
+    /// Length computed with `f32::hypot`, which doesn't overflow or underflow in the
+    /// intermediate squares. Prefer it over `length` when a component can exceed
+    /// about `1e19` or is tiny, at the cost of speed
+    pub fn length_robust(&self) -> f32 {
+        self.x().hypot(self.y())
+    }
+
+    pub fn from_polar(radius: f32, angle: f32) -> Self {
+        let (sin, cos) = angle.sin_cos();
+
+        Self([radius * cos, radius * sin])
+    }
+
+    /// Orders by x only, see `total_cmp`
+    pub fn cmp_by_x(&self, rhs: &Self) -> core::cmp::Ordering {
+        self.x().total_cmp(&rhs.x())
+    }
+
+    /// Orders by y only, see `total_cmp`
+    pub fn cmp_by_y(&self, rhs: &Self) -> core::cmp::Ordering {
+        self.y().total_cmp(&rhs.y())
+    }
+
+    /// Counterclockwise quarter turn `(-y, x)`. Exact, unlike a rotation through trig
+    pub fn rotate90(&self) -> Self {
+        Self([-self.y(), self.x()])
+    }
+
+    pub fn rotate180(&self) -> Self {
+        -*self
+    }
+
+    /// Unit vector perpendicular to `self`, the normalized `rotate90`. Uses
+    /// `length_robust`, so tiny vectors still give a unit result.
+    /// The zero vector gives the zero vector
+    pub fn any_perpendicular_normalized(&self) -> Self {
+        if *self == Self::ZERO {
+            return Self::ZERO;
+        }
+
+        self.rotate90() / self.length_robust()
+    }
+
+    /// Clockwise quarter turn `(y, -x)`
+    pub fn rotate270(&self) -> Self {
+        Self([self.y(), -self.x()])
+    }
+
+    /// Angle from the positive x axis in `[-pi, pi]`. The zero vector gives `0.0`
+    pub fn angle(&self) -> f32 {
+        if self.x() == 0.0 && self.y() == 0.0 {
+            return 0.0;
+        }
+
+        self.y().atan2(self.x())
+    }
+
+    /// `(length, angle)`, see `angle`
+    pub fn to_polar(&self) -> (f32, f32) {
+        (self.length(), self.angle())
+    }
+
+    pub const fn xx(&self) -> Self {
+        Self([self.0[0], self.0[0]])
+    }
+
+    pub const fn xy(&self) -> Self {
+        *self
+    }
+
+    /// Swaps the components, mirroring across the `y = x` diagonal
+    pub const fn yx(&self) -> Self {
+        Self([self.0[1], self.0[0]])
+    }
+
+    pub const fn yy(&self) -> Self {
+        Self([self.0[1], self.0[1]])
+    }
+
+    /// Lossless conversion to `f64` components. Mixing `Vec2` and `DVec2` in
+    /// arithmetic needs this or `DVec2::as_vec2`, there is no implicit coercion
+    pub fn as_dvec2(&self) -> DVec2 {
+        DVector(self.0.map(f64::from))
+    }
+
+    /// Rounds both components to the nearest integer, halves away from zero.
+    /// Out of range components saturate and NaN becomes `0`
+    pub fn as_ivec2(&self) -> IVec2 {
+        IVector(self.0.map(|val| val.round() as i32))
+    }
+
+    /// Counterclockwise rotation by `angle` radians
+    pub fn rotate(&self, angle: f32) -> Self {
+        let (sin, cos) = angle.sin_cos();
+
+        Self([
+            self.x() * cos - self.y() * sin,
+            self.x() * sin + self.y() * cos,
+        ])
+    }
+
+    /// z component of the 3D cross product, `|self| * |rhs| * sin(angle)`
+    pub fn perp_dot(&self, rhs: &Self) -> f32 {
+        self.x() * rhs.y() - self.y() * rhs.x()
+    }
+
+    /// `|perp_dot| <= eps`. The zero vector is parallel to everything
+    pub fn is_parallel(&self, rhs: &Self, eps: f32) -> bool {
+        self.perp_dot(rhs).abs() <= eps
+    }
+
+    /// Unsigned angle between the vectors in `[0, pi]`. Uses `atan2`, which stays
+    /// accurate for nearly parallel vectors where `acos` of `cos_angle` doesn't.
+    /// Gives `0.0` if either vector is zero
+    pub fn angle_between(&self, other: &Self) -> f32 {
+        // the dot product with a zero vector can be `-0.0`, and `atan2(0.0, -0.0)` is `pi`
+        if *self == Self::ZERO || *other == Self::ZERO {
+            return 0.0;
+        }
+
+        self.perp_dot(other).abs().atan2(self.dot(other))
+    }
+
+    /// Signed angle in `[-pi, pi]` to turn `self` towards `other`, positive when
+    /// the turn is counterclockwise
+    pub fn signed_angle_to(&self, other: &Self) -> f32 {
+        self.perp_dot(other).atan2(self.dot(other))
+    }
+
+    /// Projection onto `rhs`. Projecting onto the zero vector gives NaN components
+    pub fn project_onto(&self, rhs: &Self) -> Self {
+        *rhs * (self.dot(rhs) / rhs.length_squared())
+    }
+
+    /// Same as `project_onto`, but `unit` must already be normalized.
+    /// Otherwise the result is scaled by `unit.length_squared()`
+    pub fn project_onto_normalized(&self, unit: &Self) -> Self {
+        *unit * self.dot(unit)
+    }
+
+    /// Interpolates the angle along the shorter arc and the length linearly
+    pub fn slerp(&self, other: &Self, t: f32) -> Self {
+        let (from_len, from_angle) = self.to_polar();
+        let (to_len, to_angle) = other.to_polar();
+
+        let mut delta = to_angle - from_angle;
+        if delta > core::f32::consts::PI {
+            delta -= core::f32::consts::TAU;
+        } else if delta < -core::f32::consts::PI {
+            delta += core::f32::consts::TAU;
+        }
+
+        Self::from_polar(from_len + (to_len - from_len) * t, from_angle + delta * t)
+    }
+}
+
+impl Vector<3> {
+    pub const fn new(x: f32, y: f32, z: f32) -> Self {
+        Self([x, y, z])
+    }
+
+    pub const fn x(&self) -> f32 {
+        self.0[0]
+    }
+
+    pub const fn y(&self) -> f32 {
+        self.0[1]
+    }
+
+    pub const fn z(&self) -> f32 {
+        self.0[2]
+    }
+
+    pub fn cross(&self, rhs: &Self) -> Self {
+        Self([
+            self.y() * rhs.z() - self.z() * rhs.y(),
+            self.z() * rhs.x() - self.x() * rhs.z(),
+            self.x() * rhs.y() - self.y() * rhs.x(),
+        ])
+    }
+
+    /// Two unit vectors that together with the normalized `self` form an orthonormal
+    /// basis. Continuous everywhere except at `z == 0` (Duff et al. 2017).
+    /// The zero vector gives a pair of zero vectors
+    pub fn any_orthonormal_pair(&self) -> (Self, Self) {
+        if *self == Self::ZERO {
+            return (Self::ZERO, Self::ZERO);
+        }
+
+        let [x, y, z] = self.normalize().0;
+        let sign = 1.0f32.copysign(z);
+        let a = -1.0 / (sign + z);
+        let b = x * y * a;
+
+        (
+            Self([1.0 + sign * x * x * a, sign * b, -sign * x]),
+            Self([b, sign + y * y * a, -y]),
+        )
+    }
+}
+
+impl Vector<4> {
+    pub const fn new(x: f32, y: f32, z: f32, w: f32) -> Self {
+        Self([x, y, z, w])
+    }
+
+    pub const fn x(&self) -> f32 {
+        self.0[0]
+    }
+
+    pub const fn y(&self) -> f32 {
+        self.0[1]
+    }
+
+    pub const fn z(&self) -> f32 {
+        self.0[2]
+    }
+
+    pub const fn w(&self) -> f32 {
+        self.0[3]
+    }
+}
+
+impl<const N: usize> DVector<N> {
+    pub fn dot(&self, rhs: &Self) -> f64 {
+        (0..N).map(|i| self.0[i] * rhs.0[i]).sum()
+    }
+
+    pub fn length_squared(&self) -> f64 {
+        self.dot(self)
+    }
+
+    pub fn length(&self) -> f64 {
+        self.length_squared().sqrt()
+    }
+
+    /// The zero vector has no direction, so normalizing it gives NaN components
+    pub fn normalize(&self) -> Self {
+        *self / self.length()
+    }
+}
+
+impl DVector<2> {
+    pub const fn new(x: f64, y: f64) -> Self {
+        Self([x, y])
+    }
+
+    pub const fn x(&self) -> f64 {
+        self.0[0]
+    }
+
+    pub const fn y(&self) -> f64 {
+        self.0[1]
+    }
+
+    /// Rounds both components to the nearest `f32`
+    pub fn as_vec2(&self) -> Vec2 {
+        Vector(self.0.map(|val| val as f32))
+    }
+}
+
+impl<const N: usize> IVector<N> {
+    pub fn min(&self, rhs: &Self) -> Self {
+        Self(core::array::from_fn(|i| self.0[i].min(rhs.0[i])))
+    }
+
+    pub fn max(&self, rhs: &Self) -> Self {
+        Self(core::array::from_fn(|i| self.0[i].max(rhs.0[i])))
+    }
+
+    /// Panics if `min[i] > max[i]` for any component
+    pub fn clamp(&self, min: &Self, max: &Self) -> Self {
+        Self(core::array::from_fn(|i| {
+            self.0[i].clamp(min.0[i], max.0[i])
+        }))
+    }
+
+    /// Overflows on `i32::MIN` components, like `i32::abs`
+    pub fn abs(&self) -> Self {
+        Self(self.0.map(i32::abs))
+    }
+}
+
+impl IVector<2> {
+    pub const fn new(x: i32, y: i32) -> Self {
+        Self([x, y])
+    }
+
+    pub const fn x(&self) -> i32 {
+        self.0[0]
+    }
+
+    pub const fn y(&self) -> i32 {
+        self.0[1]
+    }
+
+    /// Exact for components up to `2^24` in magnitude
+    pub fn as_vec2(&self) -> Vec2 {
+        Vector(self.0.map(|val| val as f32))
+    }
+}
+
+/// Prints `(x, y, ...)`. Precision and other flags apply to every component,
+/// so `{:.2}` rounds each of them
+impl<const N: usize> core::fmt::Display for Vector<N> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "(")?;
+
+        for (i, val) in self.0.iter().enumerate() {
+            if i > 0 {
+                write!(f, ", ")?;
+            }
+
+            core::fmt::Display::fmt(val, f)?;
+        }
+
+        write!(f, ")")
+    }
+}
+
+macro_rules! impl_tuple_conversions {
+    ($($n:literal: ($($field:ident),*));*) => {
+        $(
+            impl From<($(impl_tuple_conversions!(@f32 $field)),*)> for Vector<$n> {
+                fn from(($($field),*): ($(impl_tuple_conversions!(@f32 $field)),*)) -> Self {
+                    Self([$($field),*])
+                }
+            }
+
+            impl From<Vector<$n>> for ($(impl_tuple_conversions!(@f32 $field)),*) {
+                fn from(vec: Vector<$n>) -> Self {
+                    let [$($field),*] = vec.0;
+
+                    ($($field),*)
+                }
+            }
+        )*
+    };
+
+    (@f32 $field:ident) => { f32 };
+}
+
+impl_tuple_conversions!(2: (x, y); 3: (x, y, z); 4: (x, y, z, w));
+
+impl<const N: usize> Dot for Vector<N> {
+    type Output = f32;
+
+    fn dot(&self, rhs: &Self) -> f32 {
+        Vector::dot(self, rhs)
+    }
+}
+
+impl<const N: usize> Dot for DVector<N> {
+    type Output = f64;
+
+    fn dot(&self, rhs: &Self) -> f64 {
+        DVector::dot(self, rhs)
+    }
+}
+
+/// Overflows like `i32` arithmetic
+impl<const N: usize> Dot for IVector<N> {
+    type Output = i32;
+
+    fn dot(&self, rhs: &Self) -> i32 {
+        (0..N).map(|i| self.0[i] * rhs.0[i]).sum()
+    }
+}
+
+/// Raises every component to `exp`, see `f32::powf`
+impl<const N: usize> Pow<f32> for Vector<N> {
+    type Output = Self;
+
+    fn pow(self, exp: f32) -> Self::Output {
+        self.powf(exp)
+    }
+}
+
+/// Reciprocal of every component. Zero components become infinite, there is no
+/// failure case
+impl<const N: usize> Inv for Vector<N> {
+    type Output = Self;
+
+    fn inv(self) -> Self::Output {
+        self.recip()
+    }
+}
+
+/// Component-wise sign operations. A vector is positive or negative only when
+/// every component is, so mixed signs are neither. `signum` follows `f32::signum`,
+/// which maps `0.0` to `1.0` and `-0.0` to `-1.0`
+impl<const N: usize> Signed for Vector<N> {
+    /// Never fails
+    fn abs(&self) -> Option<Self> {
+        Some(self.map(f32::abs))
+    }
+
+    fn signum(&self) -> Self {
+        Vector::signum(self)
+    }
+
+    fn is_positive(&self) -> bool {
+        self.0.iter().all(|&val| val > 0.0)
+    }
+
+    fn is_negative(&self) -> bool {
+        self.0.iter().all(|&val| val < 0.0)
+    }
+}
+
+/// Normalizes every vector in `v`. Vectors are processed in groups of four so the
+/// compiler can vectorize the square roots and multiplications
+pub fn normalize_in_place(v: &mut [Vec2]) {
+    let mut chunks = v.chunks_exact_mut(4);
+
+    for chunk in &mut chunks {
+        let inv_len: [f32; 4] = core::array::from_fn(|i| 1.0 / chunk[i].length());
+
+        for (vec, inv_len) in chunk.iter_mut().zip(inv_len) {
+            *vec *= inv_len;
+        }
+    }
+
+    for vec in chunks.into_remainder() {
+        *vec = vec.normalize();
+    }
+}
+
+/// Closest point to `p` on the segment from `a` to `b`. Points beyond an end
+/// project onto that end. A degenerate segment with `a == b` gives `a`
+pub fn project_point_onto_segment<const N: usize>(
+    p: Vector<N>,
+    a: Vector<N>,
+    b: Vector<N>,
+) -> Vector<N> {
+    let ab = b - a;
+    let len_sq = ab.length_squared();
+
+    if len_sq == 0.0 {
+        return a;
+    }
+
+    let t = ((p - a).dot(&ab) / len_sq).clamp(0.0, 1.0);
+
+    a + ab * t
+}
+
+/// Distance from `p` to the closest point of the segment from `a` to `b`,
+/// see `project_point_onto_segment`
+pub fn distance_to_segment<const N: usize>(p: Vector<N>, a: Vector<N>, b: Vector<N>) -> f32 {
+    (p - project_point_onto_segment(p, a, b)).length()
+}
+
+// Offset of the point at `i` in an interleaved buffer. Checked, so a huge index
+// panics instead of wrapping around to the start of the buffer in release builds
+fn xy_offset(i: usize) -> usize {
+    i.checked_mul(2).expect("Index out of bounds")
+}
+
+/// Reads the points at `indices` from `data`, which holds interleaved `x, y` pairs.
+/// Panics if an index points past the last full pair
+pub fn gather_xy(data: &[f32], indices: &[usize]) -> Vec<Vec2> {
+    indices
+        .iter()
+        .map(|&i| {
+            let at = xy_offset(i);
+            Vec2::new(data[at], data[at + 1])
+        })
+        .collect()
+}
+
+/// Writes `values[k]` to the point at `indices[k]` in `data`, see `gather_xy`.
+/// A repeated index keeps the last value written to it.
+/// Panics if the slices differ in length or an index is out of bounds
+pub fn scatter_xy(data: &mut [f32], indices: &[usize], values: &[Vec2]) {
+    assert_eq!(
+        indices.len(),
+        values.len(),
+        "Indices and values differ in length"
+    );
+
+    for (&i, val) in indices.iter().zip(values) {
+        data[xy_offset(i)..][..2].copy_from_slice(val.as_array());
+    }
+}
+
+/// Running sum of vectors using Kahan–Babuška (Neumaier) compensation per component.
+/// The rounding error of each addition is tracked separately, so long sums stay close
+/// to the exact result instead of drifting like a plain `f32` accumulator
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct CompensatedSum<const N: usize> {
+    sum: Vector<N>,
+    compensation: Vector<N>,
+}
+
+impl<const N: usize> CompensatedSum<N> {
+    pub const fn new() -> Self {
+        Self {
+            sum: Vector::ZERO,
+            compensation: Vector::ZERO,
+        }
+    }
+
+    pub fn add(&mut self, val: Vector<N>) {
+        for i in 0..N {
+            let sum = self.sum[i];
+            let new_sum = sum + val[i];
+
+            // recover the low-order bits lost by the larger operand
+            self.compensation[i] += if sum.abs() >= val[i].abs() {
+                (sum - new_sum) + val[i]
+            } else {
+                (val[i] - new_sum) + sum
+            };
+
+            self.sum[i] = new_sum;
+        }
+    }
+
+    pub fn total(&self) -> Vector<N> {
+        self.sum + self.compensation
+    }
+}
+
+/// Compensated sum of all vectors in `iter`, see `CompensatedSum`
+pub fn kahan_sum<const N: usize>(iter: impl IntoIterator<Item = Vector<N>>) -> Vector<N> {
+    let mut acc = CompensatedSum::new();
+
+    for val in iter {
+        acc.add(val);
+    }
+
+    acc.total()
+}
+
+/// Sum of the pairwise dot products `a[i].dot(b[i])`. Four pairs are accumulated
+/// in independent lanes so the compiler can vectorize the loop, which also means
+/// the rounding differs slightly from a sequential sum.
+/// Panics if the slices differ in length
+pub fn dot_slices(a: &[Vec2], b: &[Vec2]) -> f32 {
+    assert_eq!(a.len(), b.len(), "Slices differ in length");
+
+    let mut lanes = [0.0; 4];
+
+    let (a_chunks, b_chunks) = (a.chunks_exact(4), b.chunks_exact(4));
+    let (a_rest, b_rest) = (a_chunks.remainder(), b_chunks.remainder());
+
+    for (a, b) in a_chunks.zip(b_chunks) {
+        for i in 0..4 {
+            lanes[i] += a[i].dot(&b[i]);
+        }
+    }
+
+    let rest: f32 = a_rest.iter().zip(b_rest).map(|(a, b)| a.dot(b)).sum();
+
+    lanes.iter().sum::<f32>() + rest
+}
+
+/// Writes `a[i].dot(b[i])` to `out[i]`, processing four pairs at a time.
+/// Panics if the three slices differ in length
+pub fn dot_zip(a: &[Vec2], b: &[Vec2], out: &mut [f32]) {
+    assert_eq!(a.len(), b.len(), "Slices differ in length");
+    assert_eq!(
+        a.len(),
+        out.len(),
+        "Output differs in length from the inputs"
+    );
+
+    let mut out_chunks = out.chunks_exact_mut(4);
+    let (a_chunks, b_chunks) = (a.chunks_exact(4), b.chunks_exact(4));
+    let (a_rest, b_rest) = (a_chunks.remainder(), b_chunks.remainder());
+
+    for ((a, b), out) in a_chunks.zip(b_chunks).zip(&mut out_chunks) {
+        let xs: [f32; 4] = core::array::from_fn(|i| a[i].x() * b[i].x());
+        let ys: [f32; 4] = core::array::from_fn(|i| a[i].y() * b[i].y());
+
+        for i in 0..4 {
+            out[i] = xs[i] + ys[i];
+        }
+    }
+
+    for ((a, b), out) in a_rest.iter().zip(b_rest).zip(out_chunks.into_remainder()) {
+        *out = a.dot(b);
+    }
+}
+
+/// Splits the points into separate x and y arrays for struct-of-arrays kernels
+pub fn deinterleave(v: &[Vec2]) -> (Vec<f32>, Vec<f32>) {
+    v.iter().map(|vec| (vec.x(), vec.y())).unzip()
+}
+
+/// Inverse of `deinterleave`. Panics if `xs` and `ys` differ in length
+pub fn interleave(xs: &[f32], ys: &[f32]) -> Vec<Vec2> {
+    assert_eq!(xs.len(), ys.len(), "Coordinate arrays differ in length");
+
+    xs.iter().zip(ys).map(|(&x, &y)| Vec2::new(x, y)).collect()
+}
+
+/// Serialized as a sequence of the components, like `[x, y]`
+#[cfg(feature = "serde")]
+impl<const N: usize> serde::Serialize for Vector<N> {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        use serde::ser::SerializeTuple;
+
+        let mut seq = serializer.serialize_tuple(N)?;
+        for val in &self.0 {
+            seq.serialize_element(val)?;
+        }
+        seq.end()
+    }
+}
+
+/// Fails unless the sequence has exactly `N` components
+#[cfg(feature = "serde")]
+impl<'de, const N: usize> serde::Deserialize<'de> for Vector<N> {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        use serde::de::{Error, SeqAccess, Visitor};
+
+        struct VectorVisitor<const N: usize>;
+
+        impl<'de, const N: usize> Visitor<'de> for VectorVisitor<N> {
+            type Value = Vector<N>;
+
+            fn expecting(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                write!(f, "a sequence of {N} floats")
+            }
+
+            fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Vector<N>, A::Error> {
+                let mut res = Vector::ZERO;
+
+                for i in 0..N {
+                    res.0[i] = seq
+                        .next_element()?
+                        .ok_or_else(|| Error::invalid_length(i, &self))?;
+                }
+
+                if seq.next_element::<f32>()?.is_some() {
+                    return Err(Error::invalid_length(N + 1, &self));
+                }
+
+                Ok(res)
+            }
+        }
+
+        deserializer.deserialize_tuple(N, VectorVisitor::<N>)
+    }
+}
+
+// SAFETY: the vectors are `repr(transparent)` over arrays of plain scalars, so
+// they have no padding and every bit pattern, all zeros included, is valid
+#[cfg(feature = "bytemuck")]
+unsafe impl<const N: usize> bytemuck::Zeroable for Vector<N> {}
+
+#[cfg(feature = "bytemuck")]
+unsafe impl<const N: usize> bytemuck::Pod for Vector<N> {}
+
+#[cfg(feature = "bytemuck")]
+unsafe impl<const N: usize> bytemuck::Zeroable for DVector<N> {}
+
+#[cfg(feature = "bytemuck")]
+unsafe impl<const N: usize> bytemuck::Pod for DVector<N> {}
+
+#[cfg(feature = "bytemuck")]
+unsafe impl<const N: usize> bytemuck::Zeroable for IVector<N> {}
+
+#[cfg(feature = "bytemuck")]
+unsafe impl<const N: usize> bytemuck::Pod for IVector<N> {}
+
+#[cfg(feature = "glam")]
+macro_rules! impl_glam_conversions {
+    ($($glam:ident: $n:literal);*) => {
+        $(
+            impl From<glam::$glam> for Vector<$n> {
+                fn from(vec: glam::$glam) -> Self {
+                    Self(vec.to_array())
+                }
+            }
+
+            impl From<Vector<$n>> for glam::$glam {
+                fn from(vec: Vector<$n>) -> Self {
+                    glam::$glam::from_array(vec.0)
+                }
+            }
+        )*
+    };
+}
+
+#[cfg(feature = "glam")]
+impl_glam_conversions!(Vec2: 2; Vec3: 3; Vec4: 4);
+
+#[cfg(feature = "approx")]
+impl<const N: usize> approx::AbsDiffEq for Vector<N> {
+    type Epsilon = f32;
+
+    fn default_epsilon() -> f32 {
+        <f32 as approx::AbsDiffEq>::default_epsilon()
+    }
+
+    fn abs_diff_eq(&self, other: &Self, epsilon: f32) -> bool {
+        (0..N).all(|i| approx::AbsDiffEq::abs_diff_eq(&self.0[i], &other.0[i], epsilon))
+    }
+}
+
+#[cfg(feature = "approx")]
+impl<const N: usize> approx::RelativeEq for Vector<N> {
+    fn default_max_relative() -> f32 {
+        <f32 as approx::RelativeEq>::default_max_relative()
+    }
+
+    fn relative_eq(&self, other: &Self, epsilon: f32, max_relative: f32) -> bool {
+        (0..N).all(|i| {
+            approx::RelativeEq::relative_eq(&self.0[i], &other.0[i], epsilon, max_relative)
+        })
+    }
+}
+
+#[cfg(feature = "approx")]
+impl<const N: usize> approx::UlpsEq for Vector<N> {
+    fn default_max_ulps() -> u32 {
+        <f32 as approx::UlpsEq>::default_max_ulps()
+    }
+
+    fn ulps_eq(&self, other: &Self, epsilon: f32, max_ulps: u32) -> bool {
+        (0..N).all(|i| approx::UlpsEq::ulps_eq(&self.0[i], &other.0[i], epsilon, max_ulps))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serde_round_trip() {
+        let v = Vec2::new(1.5, -2.0);
+        let json = serde_json::to_string(&v).unwrap();
+
+        assert_eq!(json, "[1.5,-2.0]");
+        assert_eq!(serde_json::from_str::<Vec2>(&json).unwrap(), v);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serde_rejects_wrong_length() {
+        assert!(serde_json::from_str::<Vec2>("[1.0]").is_err());
+        assert!(serde_json::from_str::<Vec2>("[1.0, 2.0, 3.0]").is_err());
+    }
+
+    #[test]
+    fn display_honors_precision() {
+        assert_eq!(format!("{:.1}", Vec2::new(1.0, 2.0)), "(1.0, 2.0)");
+        assert_eq!(format!("{}", Vec3::new(1.5, -2.0, 0.25)), "(1.5, -2, 0.25)");
+    }
+
+    #[test]
+    fn abs_diff_eq_after_rotation() {
+        let rotated = Vec2::new(1.0, 0.0).rotate(core::f32::consts::PI);
+
+        assert!(rotated.abs_diff_eq(&Vec2::new(-1.0, 0.0), 1e-6));
+        assert!(!rotated.abs_diff_eq(&Vec2::new(-1.0, 0.1), 1e-6));
+    }
+
+    #[test]
+    fn relative_eq_scales_with_magnitude() {
+        let big = Vec2::new(1e6, -1e6);
+
+        assert!(big.relative_eq(&(big + 0.05), 1e-6, 1e-6));
+        assert!(!Vec2::ONE.relative_eq(&Vec2::splat(1.05), 1e-6, 1e-6));
+        assert!(!Vec2::splat(f32::INFINITY).relative_eq(&Vec2::splat(f32::MAX), 1.0, 1.0));
+    }
+
+    #[test]
+    fn map_applies_per_component() {
+        assert_eq!(Vec2::new(4.0, 9.0).map(f32::sqrt), Vec2::new(2.0, 3.0));
+        assert_eq!(
+            Vec3::new(-1.0, 0.5, 2.0).map(|val| val.clamp(0.0, 1.0)),
+            Vec3::new(0.0, 0.5, 1.0)
+        );
+    }
+
+    #[test]
+    fn zip_map_pairs_components() {
+        let res = Vec2::new(2.0, 3.0).zip_map(&Vec2::new(3.0, 2.0), f32::powf);
+
+        assert_eq!(res, Vec2::new(8.0, 9.0));
+    }
+
+    #[test]
+    fn vec3_cross_of_axes() {
+        let x = Vec3::new(1.0, 0.0, 0.0);
+        let y = Vec3::new(0.0, 1.0, 0.0);
+
+        assert_eq!(x.cross(&y), Vec3::new(0.0, 0.0, 1.0));
+        assert_eq!(y.cross(&x), Vec3::new(0.0, 0.0, -1.0));
+    }
+
+    #[test]
+    fn vec3_mirrors_vec2_api() {
+        let mut v = Vec3::new(2.0, 3.0, 6.0);
+
+        assert_eq!((v.x(), v.y(), v.z()), (2.0, 3.0, 6.0));
+        assert_eq!(v.length(), 7.0);
+        assert_eq!(v.dot(&Vec3::ONE), 11.0);
+        assert!(v.normalize().abs_diff_eq(&(v / 7.0), 1e-6));
+        assert_eq!(-v + v, Vec3::ZERO);
+        assert_eq!(v * Vec3::splat(2.0), v + v);
+
+        v[2] = 0.0;
+        assert_eq!(v[2], 0.0);
+    }
+
+    #[test]
+    fn vec4_conversions() {
+        let v = Vec4::new(1.0, 2.0, 3.0, 4.0);
+
+        assert_eq!((v.x(), v.y(), v.z(), v.w()), (1.0, 2.0, 3.0, 4.0));
+        assert_eq!(Vec4::from((1.0, 2.0, 3.0, 4.0)), v);
+        assert_eq!(<(f32, f32, f32, f32)>::from(v), (1.0, 2.0, 3.0, 4.0));
+        assert_eq!(Vec4::from([1.0, 2.0, 3.0, 4.0]), v);
+        assert_eq!(<[f32; 4]>::from(v), [1.0, 2.0, 3.0, 4.0]);
+    }
+
+    #[test]
+    fn vec4_mirrors_vec2_api() {
+        let mut v = Vec4::new(1.0, 1.0, 1.0, 1.0);
+
+        assert_eq!(v.length(), 2.0);
+        assert_eq!(v.normalize(), Vec4::splat(0.5));
+        assert_eq!(v.dot(&Vec4::new(1.0, 2.0, 3.0, 4.0)), 10.0);
+        assert_eq!(v - Vec4::ONE, Vec4::ZERO);
+
+        v *= 3.0;
+        v[3] = 0.0;
+        assert_eq!(v, Vec4::new(3.0, 3.0, 3.0, 0.0));
+    }
+
+    #[test]
+    fn dvec2_keeps_precision() {
+        // 2^24 + 1 has no exact `f32` representation
+        assert_eq!((Vec2::splat(16_777_216.0) + 1.0).x(), 16_777_216.0);
+        assert_eq!((DVec2::splat(16_777_216.0) + 1.0).x(), 16_777_217.0);
+
+        let third = DVec2::new(1.0, 2.0) / 3.0;
+        assert_eq!(third.x(), 1.0 / 3.0);
+        assert_ne!(third.as_vec2().as_dvec2().x(), 1.0 / 3.0);
+    }
+
+    #[test]
+    fn dvec2_mirrors_vec2_api() {
+        let v = DVec2::new(3.0, 4.0);
+
+        assert_eq!(v.length(), 5.0);
+        assert_eq!(v.dot(&DVec2::ONE), 7.0);
+        assert_eq!(v.normalize(), DVec2::new(0.6, 0.8));
+        assert_eq!(-v + v, DVec2::ZERO);
+        assert_eq!(v[1], 4.0);
+        assert_eq!(Vec2::new(0.5, 0.25).as_dvec2(), DVec2::new(0.5, 0.25));
+    }
+
+    #[test]
+    fn ivec2_snaps_to_grid() {
+        let cell = 16;
+        let pos = Vec2::new(37.9, -7.4);
+
+        let snapped = (pos / cell as f32).as_ivec2() * cell;
+        assert_eq!(snapped, IVec2::new(32, 0));
+        assert_eq!(snapped.as_vec2(), Vec2::new(32.0, 0.0));
+
+        assert_eq!(Vec2::new(2.5, -2.5).as_ivec2(), IVec2::new(3, -3));
+    }
+
+    #[test]
+    fn ivec2_ops_follow_i32() {
+        let v = IVec2::new(-7, 9);
+
+        assert_eq!(v / 2, IVec2::new(-3, 4));
+        assert_eq!(v % 4, IVec2::new(-3, 1));
+        assert_eq!(-v, IVec2::new(7, -9));
+        assert_eq!(v.abs(), IVec2::new(7, 9));
+        assert_eq!(v.min(&IVec2::ZERO), IVec2::new(-7, 0));
+        assert_eq!(v.max(&IVec2::ZERO), IVec2::new(0, 9));
+        assert_eq!(v.clamp(&-IVec2::ONE, &IVec2::ONE), IVec2::new(-1, 1));
+        assert_eq!(v[1], 9);
+    }
+
+    #[test]
+    fn vec2_swizzles() {
+        let v = Vec2::new(1.0, 2.0);
+
+        assert_eq!(v.yx(), Vec2::new(2.0, 1.0));
+        assert_eq!(v.xy(), v);
+        assert_eq!(v.xx(), Vec2::splat(1.0));
+        assert_eq!(v.yy(), Vec2::splat(2.0));
+        assert_eq!(v.yx().yx(), v);
+    }
+
+    #[test]
+    fn angle_between_perpendicular_and_antiparallel() {
+        use core::f32::consts::{FRAC_PI_2, PI};
+
+        assert_eq!(Vec2::X.angle_between(&Vec2::Y), FRAC_PI_2);
+        assert_eq!(Vec2::Y.angle_between(&Vec2::X), FRAC_PI_2);
+        assert_eq!(Vec2::X.signed_angle_to(&Vec2::Y), FRAC_PI_2);
+        assert_eq!(Vec2::Y.signed_angle_to(&Vec2::X), -FRAC_PI_2);
+
+        let v = Vec2::new(3.0, -4.0);
+        assert_eq!(v.angle_between(&-v), PI);
+        assert_eq!(v.angle_between(&(v * 2.0)), 0.0);
+        assert_eq!(v.angle_between(&Vec2::ZERO), 0.0);
+        assert_eq!(Vec2::ZERO.angle_between(&Vec2::new(-1.0, -1.0)), 0.0);
+        assert_eq!(Vec2::new(-1.0, -1.0).angle_between(&Vec2::ZERO), 0.0);
+        assert_eq!(Vec2::NEG_X.angle_between(&-Vec2::ZERO), 0.0);
+    }
+
+    #[test]
+    fn pow_is_component_wise() {
+        assert_eq!(Vec2::new(3.0, -2.0).pow(2.0), Vec2::new(9.0, 4.0));
+        assert_eq!(Vec2::new(4.0, 9.0).pow(0.5), Vec2::new(2.0, 3.0));
+        assert!(Vec2::new(-4.0, 1.0).pow(0.5).x().is_nan());
+    }
+
+    #[test]
+    fn inv_is_component_wise() {
+        assert_eq!(Vec2::new(4.0, -0.5).inv(), Vec2::new(0.25, -2.0));
+        assert_eq!(
+            Vec2::new(0.0, -0.0).inv(),
+            Vec2::new(f32::INFINITY, f32::NEG_INFINITY)
+        );
+    }
+
+    #[test]
+    fn signed_needs_every_component() {
+        let mixed = Vec2::new(-1.0, 2.0);
+
+        assert!(!mixed.is_negative());
+        assert!(!mixed.is_positive());
+        assert!((-Vec2::ONE).is_negative());
+        assert!(Vec2::ONE.is_positive());
+        assert!(!Vec2::ZERO.is_positive() && !Vec2::ZERO.is_negative());
+        assert_eq!(mixed.abs(), Some(Vec2::new(1.0, 2.0)));
+        assert_eq!(Vec2::new(-3.0, 0.0).signum(), Vec2::new(-1.0, 1.0));
+    }
+
+    #[cfg(feature = "bytemuck")]
+    #[test]
+    fn bytemuck_cast_slice() {
+        let verts = [Vec2::new(1.0, 2.0), Vec2::new(3.0, 4.0)];
+
+        assert_eq!(
+            bytemuck::cast_slice::<Vec2, f32>(&verts),
+            &[1.0, 2.0, 3.0, 4.0]
+        );
+        assert_eq!(
+            bytemuck::cast_slice::<f32, Vec3>(&[1.0, 2.0, 3.0]),
+            &[Vec3::new(1.0, 2.0, 3.0)]
+        );
+        assert_eq!(<IVec2 as bytemuck::Zeroable>::zeroed(), IVec2::ZERO);
+    }
+
+    #[cfg(feature = "glam")]
+    #[test]
+    fn glam_round_trip() {
+        let v = Vec2::new(1.5, -2.0);
+        let g: glam::Vec2 = v.into();
+
+        assert_eq!(g, glam::Vec2::new(1.5, -2.0));
+        assert_eq!(Vec2::from(g), v);
+        assert_eq!(
+            Vec3::from(glam::Vec3::new(1.0, 2.0, 3.0)),
+            Vec3::new(1.0, 2.0, 3.0)
+        );
+        assert_eq!(
+            glam::Vec4::from(Vec4::new(1.0, 2.0, 3.0, 4.0)),
+            glam::Vec4::new(1.0, 2.0, 3.0, 4.0)
+        );
+    }
+
+    fn sample_points(len: usize) -> Vec<Vec2> {
+        (0..len)
+            .map(|i| Vec2::new(i as f32, 3.0 - i as f32 * 0.5))
+            .collect()
+    }
+
+    #[test]
+    fn dot_slices_matches_scalar_loop() {
+        // lengths around the chunk size exercise the remainder
+        for len in [0, 1, 3, 4, 5, 8, 11] {
+            let (a, b) = (sample_points(len), sample_points(len + 1)[1..].to_vec());
+            let naive: f32 = a.iter().zip(&b).map(|(a, b)| a.dot(b)).sum();
+
+            assert_eq!(dot_slices(&a, &b), naive);
+        }
+    }
+
+    #[test]
+    fn dot_zip_matches_scalar_loop() {
+        for len in [0, 3, 4, 7, 9] {
+            let (a, b) = (sample_points(len), sample_points(len + 2)[2..].to_vec());
+            let mut out = vec![f32::NAN; len];
+
+            dot_zip(&a, &b, &mut out);
+
+            let naive: Vec<f32> = a.iter().zip(&b).map(|(a, b)| a.dot(b)).collect();
+            assert_eq!(out, naive);
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "Slices differ in length")]
+    fn dot_slices_rejects_mismatched_lengths() {
+        dot_slices(&sample_points(3), &sample_points(4));
+    }
+
+    #[test]
+    fn deinterleave_round_trip() {
+        let points = sample_points(5);
+        let (xs, ys) = deinterleave(&points);
+
+        assert_eq!(xs, [0.0, 1.0, 2.0, 3.0, 4.0]);
+        assert_eq!(ys, [3.0, 2.5, 2.0, 1.5, 1.0]);
+        assert_eq!(interleave(&xs, &ys), points);
+        assert_eq!(deinterleave(&[]), (vec![], vec![]));
+    }
+
+    #[test]
+    #[should_panic(expected = "Coordinate arrays differ in length")]
+    fn interleave_rejects_mismatched_lengths() {
+        interleave(&[1.0, 2.0], &[1.0]);
+    }
+
+    fn cosine_similarity<V: Dot<Output = f32>>(a: &V, b: &V) -> f32 {
+        a.dot(b) / (a.dot(a) * b.dot(b)).sqrt()
+    }
+
+    #[test]
+    fn dot_trait_is_generic() {
+        assert_eq!(cosine_similarity(&Vec2::X, &Vec2::Y), 0.0);
+        assert_eq!(
+            cosine_similarity(&Vec2::new(3.0, 4.0), &Vec2::new(6.0, 8.0)),
+            1.0
+        );
+        assert_eq!(
+            cosine_similarity(&Vec3::new(1.0, 0.0, 0.0), &Vec3::new(-2.0, 0.0, 0.0)),
+            -1.0
+        );
+
+        assert_eq!(Dot::dot(&DVec2::new(0.5, 2.0), &DVec2::ONE), 2.5);
+        assert_eq!(Dot::dot(&IVec2::new(2, -3), &IVec2::new(4, 5)), -7);
+    }
+
+    #[test]
+    fn clamp_length_caps_and_extends() {
+        let long = Vec2::new(30.0, 40.0);
+        let short = Vec2::new(0.3, 0.4);
+
+        assert_eq!(long.clamp_length_max(5.0), Vec2::new(3.0, 4.0));
+        assert_eq!(short.clamp_length_max(5.0), short);
+        assert_eq!(short.clamp_length_min(5.0), Vec2::new(3.0, 4.0));
+        assert_eq!(long.clamp_length_min(5.0), long);
+
+        assert_eq!(long.clamp_length(1.0, 5.0), Vec2::new(3.0, 4.0));
+        assert_eq!(short.clamp_length(5.0, 10.0), Vec2::new(3.0, 4.0));
+        assert_eq!(
+            Vec2::new(3.0, 4.0).clamp_length(1.0, 10.0),
+            Vec2::new(3.0, 4.0)
+        );
+
+        assert_eq!(Vec2::ZERO.clamp_length_min(1.0), Vec2::ZERO);
+        assert_eq!(Vec2::ZERO.clamp_length(1.0, 2.0), Vec2::ZERO);
+    }
+
+    #[test]
+    fn finiteness_predicates() {
+        let zero = 0.0;
+        let nan = Vec2::new(zero / zero, 1.0);
+
+        assert!(nan.is_nan());
+        assert!(!nan.is_finite());
+        assert!(!nan.is_normalized());
+        assert!(!Vec2::new(f32::INFINITY, 0.0).is_finite());
+        assert!(!Vec2::new(f32::INFINITY, 0.0).is_nan());
+        assert!(Vec2::new(1.0, -2.0).is_finite());
+
+        assert!(Vec2::new(3.0, 4.0).normalize().is_normalized());
+        assert!(Vec2::new(1.0, 1e-3).is_normalized());
+        assert!(!Vec2::new(3.0, 4.0).is_normalized());
+        assert!(!Vec2::ZERO.is_normalized());
+    }
+
+    #[test]
+    fn midpoint_and_move_towards() {
+        let target = Vec2::new(3.0, 4.0);
+
+        assert_eq!(Vec2::ZERO.midpoint(&target), Vec2::new(1.5, 2.0));
+        assert_eq!(Vec2::ZERO.move_towards(&target, 2.5), Vec2::new(1.5, 2.0));
+        assert_eq!(Vec2::ZERO.move_towards(&target, 100.0), target);
+        assert_eq!(target.move_towards(&target, 1.0), target);
+
+        let mut pos = Vec2::ZERO;
+        for _ in 0..4 {
+            pos = pos.move_towards(&target, 2.0);
+        }
+        assert_eq!(pos, target);
+    }
+
+    #[test]
+    fn horizontal_reductions() {
+        let v = Vec2::new(3.0, 4.0);
+
+        assert_eq!(v.element_sum(), 7.0);
+        assert_eq!(v.element_product(), 12.0);
+        assert_eq!(v.min_element(), 3.0);
+        assert_eq!(v.max_element(), 4.0);
+        assert_eq!((-v).min_element(), -4.0);
+    }
+
+    #[test]
+    fn sum_and_product_of_vectors() {
+        let vs = [Vec2::new(2.0, 3.0), Vec2::new(4.0, 5.0)];
+
+        assert_eq!(vs.into_iter().product::<Vec2>(), Vec2::new(8.0, 15.0));
+        assert_eq!(vs.iter().sum::<Vec2>(), Vec2::new(6.0, 8.0));
+        assert_eq!(core::iter::empty::<Vec2>().product::<Vec2>(), Vec2::ONE);
+        assert_eq!(core::iter::empty::<Vec2>().sum::<Vec2>(), Vec2::ZERO);
+        assert_eq!(
+            [IVec2::new(2, -1); 3].iter().product::<IVec2>(),
+            IVec2::new(8, -1)
+        );
+    }
+
+    #[test]
+    fn ivec2_bit_ops() {
+        let v = IVec2::new(0b1100, 0b1010);
+
+        assert_eq!(v ^ IVec2::splat(0b0110), IVec2::new(0b1010, 0b1100));
+        assert_eq!(v & 0b1000, IVec2::new(0b1000, 0b1000));
+        assert_eq!(0b0001 | v, IVec2::new(0b1101, 0b1011));
+
+        let mut w = v;
+        w ^= v;
+        assert_eq!(w, IVec2::ZERO);
+    }
+
+    #[test]
+    fn rem_vs_rem_euclid() {
+        let v = Vec2::new(-1.0, 5.0);
+        let three = Vec2::splat(3.0);
+
+        assert_eq!(v % three, Vec2::new(-1.0, 2.0));
+        assert_eq!(v.rem_euclid(&three), Vec2::new(2.0, 2.0));
+        assert_eq!(v.rem_euclid(&-three), Vec2::new(2.0, 2.0));
+    }
+
+    #[test]
+    fn elementary_math() {
+        let v = Vec2::new(4.0, 0.25);
+
+        assert_eq!(v.powf(1.5), Vec2::new(8.0, 0.125));
+        assert_eq!(v.sqrt(), Vec2::new(2.0, 0.5));
+        assert_eq!(v.recip(), Vec2::new(0.25, 4.0));
+        assert_eq!(Vec2::new(-0.5, 0.0).signum(), Vec2::new(-1.0, 1.0));
+        assert!(Vec2::new(-1.0, 1.0).sqrt().is_nan());
+    }
+
+    #[test]
+    fn scalar_on_the_left_keeps_operand_order() {
+        assert_eq!(5.0 - Vec2::splat(2.0), Vec2::splat(3.0));
+        assert_eq!(8.0 / Vec2::splat(2.0), Vec2::splat(4.0));
+        assert_eq!(7.0 % Vec2::new(4.0, 5.0), Vec2::new(3.0, 2.0));
+        assert_eq!(10 - IVec2::new(1, 2), IVec2::new(9, 8));
+    }
+
+    #[test]
+    fn vector_of_five() {
+        let mut v = Vector::<5>::from_array([1.0, 2.0, 3.0, 4.0, 5.0]);
+
+        assert_eq!(v.dot(&Vector::ONE), 15.0);
+        assert_eq!(v.length_squared(), 55.0);
+        assert_eq!((v * 2.0 - v)[4], 5.0);
+        assert_eq!(v.element_product(), 120.0);
+        assert_eq!(v.min_element(), 1.0);
+        assert_eq!(v.max_element(), 5.0);
+        assert!(v.normalize().is_normalized());
+        assert_eq!(Vector::<5>::default(), Vector::ZERO);
+
+        v[2] = -3.0;
+        assert_eq!(v.to_array(), [1.0, 2.0, -3.0, 4.0, 5.0]);
+    }
+
+    #[test]
+    fn polar_round_trip() {
+        for v in [
+            Vec2::new(3.0, 4.0),
+            Vec2::new(-2.0, 0.5),
+            Vec2::new(0.0, -7.0),
+        ] {
+            let (radius, angle) = v.to_polar();
+
+            assert!(Vec2::from_polar(radius, angle).abs_diff_eq(&v, 1e-5));
+        }
+
+        assert_eq!(Vec2::ZERO.to_polar(), (0.0, 0.0));
+        assert_eq!(Vec2::NEG_X.to_polar(), (1.0, core::f32::consts::PI));
+    }
+
+    #[test]
+    fn slerp_halfway_between_axes() {
+        let half = core::f32::consts::FRAC_1_SQRT_2;
+
+        let mid = Vec2::X.slerp(&Vec2::Y, 0.5);
+        assert!(mid.abs_diff_eq(&Vec2::new(half, half), 1e-6));
+        assert!(mid.is_normalized());
+
+        // the shorter arc from +y to +x goes clockwise
+        assert!(Vec2::Y
+            .slerp(&Vec2::X, 0.5)
+            .abs_diff_eq(&Vec2::new(half, half), 1e-6));
+        assert!(Vec2::X.slerp(&Vec2::Y, 0.0).abs_diff_eq(&Vec2::X, 1e-6));
+
+        let v = Vec2::new(3.0, 4.0);
+        assert_eq!(v.project_onto_normalized(&Vec2::X), Vec2::new(3.0, 0.0));
+        assert_eq!(
+            v.project_onto_normalized(&Vec2::X),
+            v.project_onto(&Vec2::X)
+        );
+    }
+
+    #[test]
+    fn normalize_in_place_matches_scalar_path() {
+        // 7 vectors, so both the chunked loop and the remainder run
+        let mut batch: Vec<Vec2> = (1..8)
+            .map(|i| Vec2::new(i as f32, 10.0 - i as f32 * 3.0))
+            .collect();
+        let expected: Vec<Vec2> = batch.iter().map(Vec2::normalize).collect();
+
+        normalize_in_place(&mut batch);
+
+        for (got, want) in batch.iter().zip(&expected) {
+            assert!(got.abs_diff_eq(want, 1e-6));
+        }
+    }
+
+    #[test]
+    fn round_ties_even_has_no_bias() {
+        assert_eq!(Vec2::new(0.5, 1.5).round(), Vec2::new(1.0, 2.0));
+        assert_eq!(Vec2::new(0.5, 1.5).round_ties_even(), Vec2::new(0.0, 2.0));
+        assert_eq!(Vec2::new(2.5, -2.5).round(), Vec2::new(3.0, -3.0));
+        assert_eq!(Vec2::new(2.5, -2.5).round_ties_even(), Vec2::new(2.0, -2.0));
+        assert_eq!(Vec2::new(2.4, 2.6).round_ties_even(), Vec2::new(2.0, 3.0));
+    }
+
+    #[test]
+    fn try_from_slice_needs_exact_length() {
+        let buf = [1.0, 2.0, 3.0];
+
+        assert_eq!(Vec2::try_from_slice(&buf[..1]), None);
+        assert_eq!(Vec2::try_from_slice(&buf[..2]), Some(Vec2::new(1.0, 2.0)));
+        assert_eq!(Vec2::try_from_slice(&buf), None);
+        assert_eq!(Vec2::try_from_slice(&buf[1..]), Some(Vec2::new(2.0, 3.0)));
+    }
+
+    #[test]
+    fn mul_add_matches_unfused() {
+        let (a, b, c) = (
+            Vec2::new(1.5, -2.0),
+            Vec2::new(4.0, 0.25),
+            Vec2::new(0.5, 3.0),
+        );
+
+        assert!(a.mul_add(&b, &c).abs_diff_eq(&(a * b + c), 1e-6));
+
+        // one rounding instead of two: x * x - 1 keeps the tiny 2^-46 term
+        let x = Vec2::splat(1.0 + f32::EPSILON);
+        let fused = x.mul_add(&x, &-Vec2::ONE);
+        assert_eq!(x * x - 1.0, Vec2::splat(2.0 * f32::EPSILON));
+        assert_eq!(
+            fused,
+            Vec2::splat(2.0 * f32::EPSILON + f32::EPSILON * f32::EPSILON)
+        );
+    }
+
+    #[test]
+    fn length_robust_survives_huge_components() {
+        let v = Vec2::new(1e38, 1e38);
+
+        assert_eq!(v.length(), f32::INFINITY);
+        assert!((v.length_robust() / (1e38 * core::f32::consts::SQRT_2) - 1.0).abs() <= 1e-6);
+
+        let tiny = Vec2::new(3e-30, 4e-30);
+        assert_eq!(tiny.length(), 0.0);
+        assert!((tiny.length_robust() / 5e-30 - 1.0).abs() <= 1e-6);
+    }
+
+    #[test]
+    fn array_and_slice_conversions() {
+        let arr = [1.0, 2.0, 3.0];
+        let v = Vec3::from(arr);
+
+        assert_eq!(<[f32; 3]>::from(v), arr);
+        assert_eq!(Vec3::try_from(&arr[..]).ok(), Some(v));
+        assert!(Vec3::try_from(&arr[..2]).is_err());
+        assert!(Vec2::try_from(&arr[..]).is_err());
+        assert_eq!(
+            DVec2::try_from(&[0.5, 1.5][..]).ok(),
+            Some(DVec2::new(0.5, 1.5))
+        );
+    }
+
+    #[test]
+    fn angle_predicates_on_axes() {
+        let x = Vec2::new(1.0, 0.0);
+        let y = Vec2::new(0.0, 3.0);
+
+        assert!(x.is_perpendicular(&y, 1e-6));
+        assert!(!x.is_parallel(&y, 1e-6));
+        assert!(x.is_parallel(&Vec2::new(-2.0, 0.0), 1e-6));
+        assert!(!x.is_perpendicular(&Vec2::new(-2.0, 0.0), 1e-6));
+
+        assert_eq!(x.cos_angle(&y), Some(0.0));
+        assert_eq!(x.cos_angle(&Vec2::new(5.0, 0.0)), Some(1.0));
+        assert_eq!(x.cos_angle(&Vec2::new(-5.0, 0.0)), Some(-1.0));
+        assert_eq!(x.cos_angle(&Vec2::ZERO), None);
+    }
+
+    #[test]
+    fn quarter_turns_cycle_the_axes() {
+        let x = Vec2::new(1.0, 0.0);
+
+        assert_eq!(x.rotate90(), Vec2::new(0.0, 1.0));
+        assert_eq!(x.rotate90().rotate90(), Vec2::new(-1.0, 0.0));
+        assert_eq!(x.rotate90().rotate90().rotate90(), Vec2::new(0.0, -1.0));
+        assert_eq!(x.rotate90().rotate90().rotate90().rotate90(), x);
+
+        assert_eq!(x.rotate180(), Vec2::new(-1.0, 0.0));
+        assert_eq!(x.rotate270(), Vec2::new(0.0, -1.0));
+
+        let v = Vec2::new(3.0, -7.0);
+        assert_eq!(v.rotate90().rotate270(), v);
+    }
+
+    #[test]
+    fn sort_points_by_x_then_y() {
+        let mut points = [
+            Vec2::new(2.0, 1.0),
+            Vec2::new(1.0, 3.0),
+            Vec2::new(2.0, -1.0),
+            Vec2::new(1.0, 0.0),
+        ];
+
+        points.sort_unstable_by(Vec2::total_cmp);
+        assert_eq!(
+            points,
+            [
+                Vec2::new(1.0, 0.0),
+                Vec2::new(1.0, 3.0),
+                Vec2::new(2.0, -1.0),
+                Vec2::new(2.0, 1.0),
+            ]
+        );
+
+        points.sort_unstable_by(Vec2::cmp_by_y);
+        assert_eq!(points[0], Vec2::new(2.0, -1.0));
+        assert_eq!(points[3], Vec2::new(1.0, 3.0));
+
+        points.sort_unstable_by(|a, b| a.cmp_by_x(b).then(a.cmp_by_y(b)));
+        assert_eq!(points[0], Vec2::new(1.0, 0.0));
+        assert_eq!(points[3], Vec2::new(2.0, 1.0));
+    }
+
+    #[test]
+    fn kahan_sum_beats_naive_sum() {
+        // every small term is below half an ulp of 1.0, so a plain sum drops all of them
+        let points = || {
+            core::iter::once(Vec2::new(1.0, -1.0))
+                .chain(core::iter::repeat_n(Vec2::new(1e-8, -1e-8), 10_000))
+        };
+        let exact = 1.0001;
+
+        let naive: Vec2 = points().sum();
+        let compensated = kahan_sum(points());
+
+        assert_eq!(naive, Vec2::new(1.0, -1.0));
+        assert!((compensated.x() - exact).abs() <= 1e-6);
+        assert!((compensated.y() + exact).abs() <= 1e-6);
+        assert!((compensated.x() - exact).abs() < (naive.x() - exact).abs());
+    }
+
+    #[test]
+    fn named_directions() {
+        const DIAGONAL: f32 = Vec2::ONE.dot_const(&Vec2::X);
+
+        assert_eq!(Vec2::X + Vec2::Y, Vec2::ONE);
+        assert_eq!(Vec2::NEG_X + Vec2::NEG_Y, -Vec2::ONE);
+        assert_eq!(Vec2::X + Vec2::NEG_X, Vec2::ZERO);
+        assert_eq!(DIAGONAL, 1.0);
+        assert_eq!(Vec2::X.dot_const(&Vec2::Y), Vec2::X.dot(&Vec2::Y));
+    }
+
+    #[test]
+    fn reflect_off_a_long_normal() {
+        let normal = Vec2::new(0.0, 2.0);
+        let v = Vec2::new(3.0, -4.0);
+
+        assert_eq!(v.reflect_unnormalized(&normal), Vec2::new(3.0, 4.0));
+        assert_eq!(v.reflect(&normal.normalize()), Vec2::new(3.0, 4.0));
+        // a non-unit normal breaks the fast path
+        assert_ne!(v.reflect(&normal), Vec2::new(3.0, 4.0));
+        assert_eq!(
+            Vec2::new(5.0, 0.0).reflect_unnormalized(&normal),
+            Vec2::new(5.0, 0.0)
+        );
+    }
+
+    #[cfg(feature = "approx")]
+    #[test]
+    fn approx_macros_compare_components() {
+        approx::assert_relative_eq!(Vec2::new(0.1 + 0.2, 1.0), Vec2::new(0.3, 1.0));
+        approx::assert_abs_diff_eq!(
+            Vec2::new(1.0, 0.0).rotate(core::f32::consts::PI),
+            Vec2::new(-1.0, 0.0),
+            epsilon = 1e-6
+        );
+        approx::assert_ulps_ne!(Vec2::new(1.0, 2.0), Vec2::new(1.0, 2.1));
+    }
+
+    #[test]
+    fn reductions_agree_across_widths() {
+        let v2 = Vec2::new(3.0, -4.0);
+        let v3 = Vec3::new(3.0, -4.0, 0.0);
+        let v4 = Vector::<4>::from_array([3.0, -4.0, 1.0, 1.0]);
+
+        assert_eq!(v2.element_sum(), v2.x() + v2.y());
+        assert_eq!(v2.element_product(), v2.x() * v2.y());
+        assert_eq!(v2.dot(&v2), v2.x() * v2.x() + v2.y() * v2.y());
+
+        assert_eq!(v3.element_sum(), v2.element_sum());
+        assert_eq!(v3.length(), v2.length());
+        assert_eq!(v3.min_element(), v2.min_element());
+        assert_eq!(v4.element_product(), v2.element_product());
+        assert_eq!(v4.max_element(), v2.max_element());
+        assert_eq!(v4.element_sum(), v2.element_sum() + 2.0);
+    }
+
+    #[test]
+    fn bits_round_trip() {
+        let subnormal = f32::MIN_POSITIVE / 4.0;
+        assert!(subnormal.is_subnormal());
+
+        let v = Vec2::new(subnormal, -1.5);
+        let bits = v.to_bits();
+
+        assert_eq!(bits, [subnormal.to_bits(), (-1.5f32).to_bits()]);
+        assert_eq!(Vec2::from_bits(bits), v);
+        assert!(Vec2::from_bits(bits)[0].is_subnormal());
+
+        // equal vectors, different bits
+        assert_ne!(
+            Vec2::new(0.0, 1.0).to_bits(),
+            Vec2::new(-0.0, 1.0).to_bits()
+        );
+        assert!(Vec2::from_bits(Vec2::new(-0.0, 1.0).to_bits())[0].is_sign_negative());
+    }
+
+    #[test]
+    fn perpendicular_bases_are_unit_length() {
+        for v in [Vec2::new(3.0, -4.0), Vec2::new(1e-30, 2e-30), Vec2::X] {
+            let perp = v.any_perpendicular_normalized();
+
+            assert!(perp.is_normalized());
+            assert!(v.is_perpendicular(&perp, 1e-6));
+        }
+        assert_eq!(Vec2::ZERO.any_perpendicular_normalized(), Vec2::ZERO);
+
+        for v in [Vec3::new(1.0, 2.0, 3.0), Vec3::new(0.0, 0.0, -5.0)] {
+            let (a, b) = v.any_orthonormal_pair();
+
+            assert!(a.is_normalized() && b.is_normalized());
+            for (lhs, rhs) in [(a, b), (a, v.normalize()), (b, v.normalize())] {
+                assert!(lhs.dot(&rhs).abs() <= 1e-6);
+            }
+        }
+    }
+
+    #[test]
+    fn copysign_and_select() {
+        let v = Vec2::new(3.0, -4.0);
+
+        assert_eq!(v.copysign(&Vec2::new(-1.0, 1.0)), Vec2::new(-3.0, 4.0));
+        assert_eq!(v.copysign(&Vec2::new(-0.0, 0.0)), Vec2::new(-3.0, 4.0));
+        assert_eq!(v.copysign(&Vec2::ONE), Vec2::new(3.0, 4.0));
+
+        let a = Vec2::new(1.0, 2.0);
+        let b = Vec2::new(10.0, 20.0);
+        assert_eq!(Vec2::select([true, false], a, b), Vec2::new(1.0, 20.0));
+        assert_eq!(Vec2::select([false, true], a, b), Vec2::new(10.0, 2.0));
+        assert_eq!(Vec2::select([true; 2], a, b), a);
+    }
+
+    #[test]
+    fn project_onto_a_segment() {
+        let (a, b) = (Vec2::new(0.0, 0.0), Vec2::new(4.0, 0.0));
+
+        assert_eq!(
+            project_point_onto_segment(Vec2::new(1.0, 3.0), a, b),
+            Vec2::new(1.0, 0.0)
+        );
+        assert_eq!(distance_to_segment(Vec2::new(1.0, 3.0), a, b), 3.0);
+
+        // beyond `b`, clamped to the end
+        assert_eq!(project_point_onto_segment(Vec2::new(7.0, 4.0), a, b), b);
+        assert_eq!(distance_to_segment(Vec2::new(7.0, 4.0), a, b), 5.0);
+        assert_eq!(project_point_onto_segment(Vec2::new(-2.0, 1.0), a, b), a);
+
+        assert_eq!(project_point_onto_segment(Vec2::new(5.0, 5.0), b, b), b);
+        assert_eq!(distance_to_segment(Vec2::new(4.0, 2.0), b, b), 2.0);
+    }
+
+    #[test]
+    fn gather_and_scatter_a_permutation() {
+        let data = [0.0, 1.0, 10.0, 11.0, 20.0, 21.0, 30.0, 31.0];
+        let perm = [2, 0, 3, 1];
+
+        let points = gather_xy(&data, &perm);
+        assert_eq!(
+            points,
+            [
+                Vec2::new(20.0, 21.0),
+                Vec2::new(0.0, 1.0),
+                Vec2::new(30.0, 31.0),
+                Vec2::new(10.0, 11.0),
+            ]
+        );
+
+        // scattering back through the same permutation restores the buffer
+        let mut out = [0.0; 8];
+        scatter_xy(&mut out, &perm, &points);
+        assert_eq!(out, data);
+    }
+
+    #[test]
+    #[should_panic(expected = "Indices and values differ in length")]
+    fn scatter_needs_a_value_per_index() {
+        scatter_xy(&mut [0.0; 4], &[0, 1], &[Vec2::ZERO]);
+    }
+
+    // `2 * i` wraps to 0 here, so an unchecked offset would read the first point
+    #[test]
+    #[should_panic(expected = "Index out of bounds")]
+    fn gather_rejects_an_index_whose_offset_overflows() {
+        gather_xy(&[1.0, 2.0], &[usize::MAX / 2 + 1]);
+    }
+
+    #[test]
+    #[should_panic(expected = "Index out of bounds")]
+    fn scatter_rejects_an_index_whose_offset_overflows() {
+        scatter_xy(&mut [0.0; 2], &[usize::MAX / 2 + 1], &[Vec2::ONE]);
+    }
+}